@@ -29,12 +29,54 @@ async fn main() {
         "deploy" => interact.deploy().await,
         "start" => interact.start(error).await,
         "createLotteryPool" => interact.create_lottery_pool().await,
+        "startWithBasisPoints" => interact.start_with_basis_points().await,
         "buy_ticket" => interact.buy_ticket().await,
         "determine_winner" => interact.determine_winner().await,
+        "claimPrize" => interact.claim_prize().await,
+        "sponsorLottery" => interact.sponsor_lottery().await,
+        "excludeSponsorshipsFromBurn" => interact.exclude_sponsorships_from_burn().await,
+        "depositNftPrize" => interact.deposit_nft_prize().await,
+        "addTicketTier" => interact.add_ticket_tier().await,
+        "setWhitelistMerkleRoot" => interact.set_whitelist_merkle_root().await,
+        "addToWhitelist" => interact.add_to_whitelist().await,
+        "removeFromWhitelist" => interact.remove_from_whitelist().await,
+        "addToBlacklist" => interact.add_to_blacklist().await,
+        "removeFromBlacklist" => interact.remove_from_blacklist().await,
+        "setRequiredCollection" => interact.set_required_collection().await,
+        "enableRollover" => interact.enable_rollover().await,
+        "setKeeperReward" => interact.set_keeper_reward().await,
+        "enableUniqueWinners" => interact.enable_unique_winners().await,
+        "pauseLottery" => interact.pause_lottery().await,
+        "unpauseLottery" => interact.unpause_lottery().await,
+        "cancelLottery" => interact.cancel_lottery().await,
+        "stopRecurrence" => interact.stop_recurrence().await,
+        "extendDeadline" => interact.extend_deadline().await,
+        "increaseTicketSupply" => interact.increase_ticket_supply().await,
+        "claimRefund" => interact.claim_refund().await,
         "status" => interact.status().await,
         "set_roles" => interact.set_roles().await,
+        "getBurnCapableTokens" => interact.burn_capable_tokens().await,
+        "setMaxDuration" => interact.set_max_duration().await,
+        "setMaxTickets" => interact.set_max_tickets().await,
+        "addAllowedTicketTokens" => interact.add_allowed_ticket_tokens().await,
+        "removeAllowedTicketTokens" => interact.remove_allowed_ticket_tokens().await,
+        "setMinTicketPrice" => interact.set_min_ticket_price().await,
+        "setFallbackFeeDestination" => interact.set_fallback_fee_destination().await,
+        "getMaxDuration" => interact.get_max_duration().await,
+        "getMaxTickets" => interact.get_max_tickets().await,
+        "pause" => interact.pause().await,
+        "unpause" => interact.unpause().await,
+        "isPaused" => interact.is_paused().await,
+        "getLotteryCreator" => interact.get_lottery_creator().await,
+        "getLotteryResults" => interact.get_lottery_results().await,
+        "getLotteryHistory" => interact.get_lottery_history().await,
+        "getClaimablePrizes" => interact.get_claimable_prizes().await,
         "getLotteryInfo" => interact.lottery_info().await,
         "getLotteryWhitelist" => interact.lottery_whitelist().await,
+        "getSponsoredPrizePool" => interact.sponsored_prize_pool().await,
+        "getRolloverPool" => interact.rollover_pool().await,
+        "getWhitelistedAddresses" => interact.get_whitelisted_addresses().await,
+        "getBlacklistedAddresses" => interact.get_blacklisted_addresses().await,
         _ => panic!("unknown command: {}", &cmd),
     }
 }
@@ -152,7 +194,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(50_000_000u64)
             .typed(proxy::LotteryProxy)
-            .start(lottery_name, token_identifier, ticket_price, opt_total_tickets, opt_deadline, opt_max_entries_per_user, opt_prize_distribution, opt_whitelist, opt_burn_percentage)
+            .start(lottery_name, token_identifier, ticket_price, opt_total_tickets, opt_deadline, opt_max_entries_per_user, opt_prize_distribution, opt_whitelist, opt_burn_percentage, OptionalValue::<usize>::None, OptionalValue::<BigUint<StaticApi>>::None, OptionalValue::<bool>::None, OptionalValue::<u64>::None, OptionalValue::<ManagedAddress<StaticApi>>::None)
             .returns(error)
             .prepare_async()
             .run()
@@ -182,7 +224,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::LotteryProxy)
-            .create_lottery_pool(lottery_name, token_identifier, ticket_price, opt_total_tickets, opt_deadline, opt_max_entries_per_user, opt_prize_distribution, opt_whitelist, opt_burn_percentage)
+            .create_lottery_pool(lottery_name, token_identifier, ticket_price, opt_total_tickets, opt_deadline, opt_max_entries_per_user, opt_prize_distribution, opt_whitelist, opt_burn_percentage, OptionalValue::<usize>::None, OptionalValue::<BigUint<StaticApi>>::None, OptionalValue::<bool>::None, OptionalValue::<u64>::None, OptionalValue::<ManagedAddress<StaticApi>>::None)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -190,12 +232,520 @@ impl ContractInteract {
 
     }
 
+    async fn start_with_basis_points(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = EgldOrEsdtTokenIdentifier::esdt(&b"SRG-10c87e"[..]);
+        let ticket_price = BigUint::<StaticApi>::from(1u128);
+        let opt_total_tickets = Option::Some(3u32);
+        let opt_deadline = Option::Some(20u64);
+        let opt_max_entries_per_user = Option::Some(1u32);
+        let prize_distribution_data: &[u16] = &[3333,3333,3334];
+        let opt_prize_distribution = Option::Some(ManagedVec::from_iter(prize_distribution_data.iter().copied()));
+        let opt_whitelist: Option<ManagedVec<StaticApi, ManagedAddress<StaticApi>>> = Option::None;
+        let opt_burn_basis_points = OptionalValue::Some(BigUint::<StaticApi>::from(250u128));
+
+        self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .start_with_basis_points(lottery_name, token_identifier, ticket_price, opt_total_tickets, opt_deadline, opt_max_entries_per_user, opt_prize_distribution, opt_whitelist, opt_burn_basis_points, OptionalValue::<usize>::None, OptionalValue::<BigUint<StaticApi>>::None, OptionalValue::<bool>::None, OptionalValue::<u64>::None, OptionalValue::<ManagedAddress<StaticApi>>::None)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+    }
+
     async fn buy_ticket(&mut self) {
         let token_id = String::new();
         let token_nonce = 0u64;
         let token_amount = BigUint::<StaticApi>::from(0u128);
 
-        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<ManagedVec<StaticApi, ManagedByteArray<StaticApi, 32>>>::None)
+            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn determine_winner(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .determine_winner(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn claim_prize(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .claim_prize(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn pause_lottery(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .pause_lottery(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn unpause_lottery(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .unpause_lottery(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn cancel_lottery(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .cancel_lottery(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn add_ticket_tier(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let ticket_price = BigUint::<StaticApi>::from(0u128);
+        let supply = 0u32;
+        let weight = 0u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .add_ticket_tier(lottery_name, ticket_price, supply, weight)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_whitelist_merkle_root(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let merkle_root = ManagedByteArray::<StaticApi, 32>::new_from_bytes(&[0u8; 32]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_whitelist_merkle_root(lottery_name, merkle_root)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn add_to_whitelist(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let addresses = MultiValueVec::from(vec![bech32::decode("")]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .add_to_whitelist(lottery_name, addresses)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn remove_from_whitelist(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let addresses = MultiValueVec::from(vec![bech32::decode("")]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .remove_from_whitelist(lottery_name, addresses)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn add_to_blacklist(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let addresses = MultiValueVec::from(vec![bech32::decode("")]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .add_to_blacklist(lottery_name, addresses)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn remove_from_blacklist(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let addresses = MultiValueVec::from(vec![bech32::decode("")]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .remove_from_blacklist(lottery_name, addresses)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_required_collection(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let collection = TokenIdentifier::from_esdt_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_required_collection(lottery_name, collection)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn deposit_nft_prize(&mut self) {
+        let token_id = String::new();
+        let token_nonce = 0u64;
+        let token_amount = BigUint::<StaticApi>::from(0u128);
+
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let rank = 0u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .deposit_nft_prize(lottery_name, rank)
+            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn sponsor_lottery(&mut self) {
+        let token_id = String::new();
+        let token_nonce = 0u64;
+        let token_amount = BigUint::<StaticApi>::from(0u128);
+
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .sponsor_lottery(lottery_name)
+            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn exclude_sponsorships_from_burn(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let excluded = true;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .exclude_sponsorships_from_burn(lottery_name, excluded)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn enable_rollover(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let rollover_percentage = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .enable_rollover(lottery_name, rollover_percentage)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn enable_unique_winners(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .enable_unique_winners(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_keeper_reward(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let keeper_reward = proxy::KeeperReward::Percentage(BigUint::<StaticApi>::from(0u128));
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_keeper_reward(lottery_name, keeper_reward)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn stop_recurrence(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .stop_recurrence(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn extend_deadline(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let new_deadline = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .extend_deadline(lottery_name, new_deadline)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn increase_ticket_supply(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let additional_tickets = 0u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .increase_ticket_supply(lottery_name, additional_tickets)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_max_duration(&mut self) {
+        let max_duration = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_max_duration(max_duration)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_max_tickets(&mut self) {
+        let max_tickets = 0u32;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_max_tickets(max_tickets)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn add_allowed_ticket_tokens(&mut self) {
+        let tokens = MultiValueVec::from(vec![EgldOrEsdtTokenIdentifier::esdt(&b""[..])]);
 
         let response = self
             .interactor
@@ -204,8 +754,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::LotteryProxy)
-            .buy_ticket(lottery_name)
-            .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
+            .add_allowed_ticket_tokens(tokens)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -214,7 +763,144 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    async fn determine_winner(&mut self) {
+    async fn remove_allowed_ticket_tokens(&mut self) {
+        let tokens = MultiValueVec::from(vec![EgldOrEsdtTokenIdentifier::esdt(&b""[..])]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .remove_allowed_ticket_tokens(tokens)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_min_ticket_price(&mut self) {
+        let token = EgldOrEsdtTokenIdentifier::esdt(&b""[..]);
+        let min_ticket_price = BigUint::<StaticApi>::from(0u128);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_min_ticket_price(token, min_ticket_price)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn set_fallback_fee_destination(&mut self) {
+        let destination = bech32::decode("");
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .set_fallback_fee_destination(destination)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn get_max_duration(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_max_duration()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn get_max_tickets(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_max_tickets()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn pause(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .pause_endpoint()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn unpause(&mut self) {
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::LotteryProxy)
+            .unpause_endpoint()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    async fn is_paused(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .paused_status()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn claim_refund(&mut self) {
         let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
 
         let response = self
@@ -224,7 +910,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::LotteryProxy)
-            .determine_winner(lottery_name)
+            .claim_refund(lottery_name)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -249,6 +935,21 @@ impl ContractInteract {
 
     }
 
+    async fn burn_capable_tokens(&mut self) {
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .burn_capable_tokens()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
     async fn set_roles(&mut self) {
         let token_identifier = TokenIdentifier::from_esdt_bytes(&b"SRG-10c87e"[..]);
 
@@ -268,6 +969,71 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
+    async fn get_lottery_creator(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_lottery_creator(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn get_lottery_results(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let round = 1usize;
+
+        self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(lottery_name, round)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+    }
+
+    async fn get_lottery_history(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_lottery_history(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+    }
+
+    async fn get_claimable_prizes(&mut self) {
+        let address = &self.wallet_address;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_claimable_prizes(address)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
     async fn lottery_info(&mut self) {
         let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
 
@@ -300,6 +1066,79 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
+    async fn get_whitelisted_addresses(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let from = 0u32;
+        let size = 0u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_whitelisted_addresses(lottery_name, from, size)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn get_blacklisted_addresses(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let from = 0u32;
+        let size = 0u32;
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .get_blacklisted_addresses(lottery_name, from, size)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn sponsored_prize_pool(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .sponsored_prize_pool(lottery_name)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
+    async fn rollover_pool(&mut self) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b""[..]);
+        let token_identifier = EgldOrEsdtTokenIdentifier::esdt(&b""[..]);
+
+        let result_value = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::LotteryProxy)
+            .rollover_pool(lottery_name, token_identifier)
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("Result: {result_value:?}");
+    }
+
 }
 
 // #[tokio::test]
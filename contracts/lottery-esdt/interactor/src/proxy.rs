@@ -72,6 +72,11 @@ where
         Arg6: ProxyArg<Option<ManagedVec<Env::Api, u8>>>,
         Arg7: ProxyArg<Option<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>>,
         Arg8: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg9: ProxyArg<OptionalValue<usize>>,
+        Arg10: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg11: ProxyArg<OptionalValue<bool>>,
+        Arg12: ProxyArg<OptionalValue<u64>>,
+        Arg13: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         lottery_name: Arg0,
@@ -83,6 +88,11 @@ where
         opt_prize_distribution: Arg6,
         opt_whitelist: Arg7,
         opt_burn_percentage: Arg8,
+        opt_min_tickets: Arg9,
+        opt_creator_fee_percentage: Arg10,
+        opt_recurring: Arg11,
+        opt_start_time: Arg12,
+        opt_fee_destination: Arg13,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -96,6 +106,11 @@ where
             .argument(&opt_prize_distribution)
             .argument(&opt_whitelist)
             .argument(&opt_burn_percentage)
+            .argument(&opt_min_tickets)
+            .argument(&opt_creator_fee_percentage)
+            .argument(&opt_recurring)
+            .argument(&opt_start_time)
+            .argument(&opt_fee_destination)
             .original_result()
     }
 
@@ -109,6 +124,11 @@ where
         Arg6: ProxyArg<Option<ManagedVec<Env::Api, u8>>>,
         Arg7: ProxyArg<Option<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>>,
         Arg8: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg9: ProxyArg<OptionalValue<usize>>,
+        Arg10: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg11: ProxyArg<OptionalValue<bool>>,
+        Arg12: ProxyArg<OptionalValue<u64>>,
+        Arg13: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         lottery_name: Arg0,
@@ -120,6 +140,11 @@ where
         opt_prize_distribution: Arg6,
         opt_whitelist: Arg7,
         opt_burn_percentage: Arg8,
+        opt_min_tickets: Arg9,
+        opt_creator_fee_percentage: Arg10,
+        opt_recurring: Arg11,
+        opt_start_time: Arg12,
+        opt_fee_destination: Arg13,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -133,85 +158,1114 @@ where
             .argument(&opt_prize_distribution)
             .argument(&opt_whitelist)
             .argument(&opt_burn_percentage)
+            .argument(&opt_min_tickets)
+            .argument(&opt_creator_fee_percentage)
+            .argument(&opt_recurring)
+            .argument(&opt_start_time)
+            .argument(&opt_fee_destination)
             .original_result()
     }
 
+    /// Same as `start`, but the prize distribution and the burn are expressed in basis points, 
+    /// where 10_000 is 100%. 
+    pub fn start_with_basis_points<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<Option<usize>>,
+        Arg4: ProxyArg<Option<u64>>,
+        Arg5: ProxyArg<Option<usize>>,
+        Arg6: ProxyArg<Option<ManagedVec<Env::Api, u16>>>,
+        Arg7: ProxyArg<Option<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>>,
+        Arg8: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg9: ProxyArg<OptionalValue<usize>>,
+        Arg10: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg11: ProxyArg<OptionalValue<bool>>,
+        Arg12: ProxyArg<OptionalValue<u64>>,
+        Arg13: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        token_identifier: Arg1,
+        ticket_price: Arg2,
+        opt_total_tickets: Arg3,
+        opt_deadline: Arg4,
+        opt_max_entries_per_user: Arg5,
+        opt_prize_distribution: Arg6,
+        opt_whitelist: Arg7,
+        opt_burn_basis_points: Arg8,
+        opt_min_tickets: Arg9,
+        opt_creator_fee_percentage: Arg10,
+        opt_recurring: Arg11,
+        opt_start_time: Arg12,
+        opt_fee_destination: Arg13,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("startWithBasisPoints")
+            .argument(&lottery_name)
+            .argument(&token_identifier)
+            .argument(&ticket_price)
+            .argument(&opt_total_tickets)
+            .argument(&opt_deadline)
+            .argument(&opt_max_entries_per_user)
+            .argument(&opt_prize_distribution)
+            .argument(&opt_whitelist)
+            .argument(&opt_burn_basis_points)
+            .argument(&opt_min_tickets)
+            .argument(&opt_creator_fee_percentage)
+            .argument(&opt_recurring)
+            .argument(&opt_start_time)
+            .argument(&opt_fee_destination)
+            .original_result()
+    }
+
+    /// The number of tickets is derived from the payment, which must be a multiple of the ticket price. 
+    /// If more tickets are requested than can be bought, the purchase is rejected, 
+    /// unless `opt_partial_fill` is set, in which case the available tickets are bought 
+    /// and the rest of the payment is sent back. 
+    /// `opt_tier` selects one of the ticket tiers of the lottery, the default tier 0 being the lottery's own ticket. 
+    /// `opt_merkle_proof` proves that the caller is on the allow-list of a lottery with a Merkle root set. 
+    /// Lotteries with a required collection also expect a token of that collection as second payment, 
+    /// which is sent back right away. 
     pub fn buy_ticket<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<bool>>,
+        Arg2: ProxyArg<OptionalValue<usize>>,
+        Arg3: ProxyArg<OptionalValue<ManagedVec<Env::Api, ManagedByteArray<Env::Api, 32usize>>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        opt_partial_fill: Arg1,
+        opt_tier: Arg2,
+        opt_merkle_proof: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("buy_ticket")
+            .argument(&lottery_name)
+            .argument(&opt_partial_fill)
+            .argument(&opt_tier)
+            .argument(&opt_merkle_proof)
+            .original_result()
+    }
+
+    pub fn determine_winner<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("determine_winner")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn claim_prize<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimPrize")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Adds the payment to the prizes of a running lottery. Anyone can sponsor a lottery, in the lottery's token. 
+    /// Sponsorships are kept apart from the ticket revenue, which is the only base of the creator fee. 
+    /// They are sent back to the sponsors if the lottery is drawn without tickets, cancelled or failed. 
+    pub fn sponsor_lottery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("sponsorLottery")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Sets whether the burn percentage of the lottery also applies to its sponsorships. 
+    /// By default, sponsorships are burned like the ticket revenue. 
+    pub fn exclude_sponsorships_from_burn<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        lottery_name: Arg0,
+        excluded: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("excludeSponsorshipsFromBurn")
+            .argument(&lottery_name)
+            .argument(&excluded)
+            .original_result()
+    }
+
+    /// Turns on the rollover mode of a running lottery. The prize pool of a draw without tickets, 
+    /// and `rollover_percentage` of the prize pool of every other draw, go to a jackpot kept under the lottery's name. 
+    /// The jackpot is added to the prizes of the next draw with tickets of a lottery with the same name and token. 
+    pub fn enable_rollover<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        rollover_percentage: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableRollover")
+            .argument(&lottery_name)
+            .argument(&rollover_percentage)
+            .original_result()
+    }
+
+    /// Makes every prize rank go to a different address, before any ticket is sold. 
+    /// If there are less ticket holders than prize ranks, the shares of the ranks left empty go to the 1st place. 
+    pub fn enable_unique_winners<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableUniqueWinners")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Sets the reward of whoever successfully calls `determine_winner`, before any ticket is sold. 
+    /// The reward is taken from the prize pool of each draw with tickets, after the burn and the creator fee, 
+    /// and is added to the claimable prizes of the caller. A fixed amount is capped at the prize pool. 
+    pub fn set_keeper_reward<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<KeeperReward<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        keeper_reward: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setKeeperReward")
+            .argument(&lottery_name)
+            .argument(&keeper_reward)
+            .original_result()
+    }
+
+    /// Returns the amount given by each sponsor of a lottery. 
+    pub fn get_sponsorships<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSponsorships")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Escrows an NFT or SFT as an extra prize for the given rank, counted from 1. 
+    /// Deposits are only accepted before any ticket is sold, so buyers know every prize up front. 
+    /// Prizes that nobody wins are given back to the creator. 
+    pub fn deposit_nft_prize<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        rank: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositNftPrize")
+            .argument(&lottery_name)
+            .argument(&rank)
+            .original_result()
+    }
+
+    /// Adds a ticket tier to a running lottery, before any ticket is sold. Returns the id of the new tier. 
+    /// Tier tickets are paid in the token of the lottery and also count towards its total tickets. 
+    pub fn add_ticket_tier<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        ticket_price: Arg1,
+        supply: Arg2,
+        weight: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTicketTier")
+            .argument(&lottery_name)
+            .argument(&ticket_price)
+            .argument(&supply)
+            .argument(&weight)
+            .original_result()
+    }
+
+    /// Restricts a running lottery to the addresses of a Merkle tree, instead of storing each of them. 
+    /// The root can only be set or replaced before any ticket is sold. 
+    /// The leaves are the keccak256 hashes of the addresses, and each pair of nodes is hashed in ascending order. 
+    /// Addresses on the explicit whitelist, if any, are still allowed without a proof. 
+    pub fn set_whitelist_merkle_root<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        merkle_root: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWhitelistMerkleRoot")
+            .argument(&lottery_name)
+            .argument(&merkle_root)
+            .original_result()
+    }
+
+    /// Adding to the whitelist of an open lottery restricts it to the added addresses. 
+    /// A lottery with a whitelist stays restricted even once every address has been removed from it. 
+    pub fn add_to_whitelist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        addresses: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToWhitelist")
+            .argument(&lottery_name)
+            .argument(&addresses)
+            .original_result()
+    }
+
+    pub fn remove_from_whitelist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        addresses: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromWhitelist")
+            .argument(&lottery_name)
+            .argument(&addresses)
+            .original_result()
+    }
+
+    /// Blacklisted addresses can't buy tickets, even if they are whitelisted. 
+    /// Tickets they have already bought stay valid. 
+    pub fn add_to_blacklist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        addresses: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToBlacklist")
+            .argument(&lottery_name)
+            .argument(&addresses)
+            .original_result()
+    }
+
+    pub fn remove_from_blacklist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        addresses: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromBlacklist")
+            .argument(&lottery_name)
+            .argument(&addresses)
+            .original_result()
+    }
+
+    /// Restricts a running lottery to the holders of an NFT or SFT of the given collection. 
+    /// The ticket token of the lottery must be an ESDT, as it is paid in the same transfer as the collection token. 
+    pub fn set_required_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        collection: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRequiredCollection")
+            .argument(&lottery_name)
+            .argument(&collection)
+            .original_result()
+    }
+
+    /// Stops the ticket sales, sponsorships and draw of a single lottery, until it is unpaused. 
+    /// Claims, refunds and cancellation stay available. 
+    pub fn pause_lottery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseLottery")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn unpause_lottery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseLottery")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Stops a running lottery. Can only be called by the creator of the lottery or the contract owner. 
+    /// Every ticket bought so far becomes refundable, and the NFT prizes can be claimed back by the creator right away. 
+    pub fn cancel_lottery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelLottery")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Stops a recurring lottery from opening a new round after the current one is drawn. 
+    /// Can only be called by the creator of the lottery or the contract owner. 
+    pub fn stop_recurrence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("stopRecurrence")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Moves the deadline of a lottery that has not ended yet further away, 
+    /// still within the maximum duration from now. Only affects the current round of a recurring lottery. 
+    pub fn extend_deadline<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        lottery_name: Arg0,
+        new_deadline: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("extendDeadline")
+            .argument(&lottery_name)
+            .argument(&new_deadline)
+            .original_result()
+    }
+
+    /// Puts more tickets on sale in a lottery that has not ended yet. 
+    /// Only affects the current round of a recurring lottery. 
+    pub fn increase_ticket_supply<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        additional_tickets: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("increaseTicketSupply")
+            .argument(&lottery_name)
+            .argument(&additional_tickets)
+            .original_result()
+    }
+
+    /// Sends back the ticket payments and sponsorships of the caller, if the lottery has been cancelled 
+    /// or has not reached its minimum tickets. 
+    /// The lottery is cleared once every ticket and sponsorship has been refunded. 
+    pub fn claim_refund<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRefund")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Makes the NFT prizes of a failed lottery claimable by its creator, without waiting for every ticket to be refunded. 
+    /// Anyone can call it, as the prizes only ever go back to the creator. 
+    pub fn reclaim_nft_prizes<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reclaimNftPrizes")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn status<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("status")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn get_lottery_results<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        round: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LotteryResults<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryResults")
+            .argument(&lottery_name)
+            .argument(&round)
+            .original_result()
+    }
+
+    pub fn get_lottery_history<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LotteryResults<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryHistory")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn get_claimable_prizes<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimablePrizes")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_lottery_creator<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryCreator")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// The prize distribution of any lottery, converted to basis points. 
+    pub fn get_prize_distribution_basis_points_view<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, u16>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPrizeDistributionBasisPoints")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Returns at most `size` whitelisted addresses, skipping the first `from` ones. 
+    pub fn get_whitelisted_addresses<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistedAddresses")
+            .argument(&lottery_name)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Returns at most `size` blacklisted addresses, skipping the first `from` ones. 
+    pub fn get_blacklisted_addresses<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBlacklistedAddresses")
+            .argument(&lottery_name)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Requests the Burn role of a token for the contract. The outcome is recorded by the callback. 
+    pub fn set_roles<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_roles")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn granted_roles<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtLocalRole>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGrantedRoles")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn burn_capable_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBurnCapableTokens")
+            .original_result()
+    }
+
+    pub fn lottery_info<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LotteryInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryInfo")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn lottery_whitelist<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryWhitelist")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Set once addresses are removed from the whitelist, so that emptying it doesn't open the lottery to everyone. 
+    pub fn whitelist_enabled<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWhitelistEnabled")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn required_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRequiredCollection")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn whitelist_merkle_root<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistMerkleRoot")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn fee_destination<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeDestination")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn ticket_tiers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         lottery_name: Arg0,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TicketTier<Env::Api>>> {
         self.wrapped_tx
-            .raw_call("buy_ticket")
+            .payment(NotPayable)
+            .raw_call("getTicketTiers")
             .argument(&lottery_name)
             .original_result()
     }
 
-    pub fn determine_winner<
+    pub fn lottery_paused<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         lottery_name: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("determine_winner")
+            .raw_call("isLotteryPaused")
             .argument(&lottery_name)
             .original_result()
     }
 
-    pub fn status<
+    /// When the current round opens, or opened. 
+    pub fn lottery_start_time<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         lottery_name: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("status")
+            .raw_call("getLotteryStartTime")
             .argument(&lottery_name)
             .original_result()
     }
 
-    pub fn set_roles<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    pub fn min_tickets<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
-        token_identifier: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("set_roles")
+            .raw_call("getMinTickets")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn lottery_recurrence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RecurrenceConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryRecurrence")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// The round that is currently open, counted from 1. 
+    pub fn round<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRound")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn sponsored_prize_pool<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSponsoredPrizePool")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn sponsorships_excluded_from_burn<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("areSponsorshipsExcludedFromBurn")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn unique_winners<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("areWinnersUnique")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn keeper_reward<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, KeeperReward<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKeeperReward")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn rollover_percentage_for_lottery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRolloverPercentageForLottery")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Kept when the lottery is cleared, so that the jackpot goes to the next lottery with the same name. 
+    pub fn rollover_pool<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        token_identifier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRolloverPool")
+            .argument(&lottery_name)
             .argument(&token_identifier)
             .original_result()
     }
 
-    pub fn lottery_info<
+    pub fn rollover_pool_creator<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
         lottery_name: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LotteryInfo<Env::Api>> {
+        token_identifier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLotteryInfo")
+            .raw_call("getRolloverPoolCreator")
             .argument(&lottery_name)
+            .argument(&token_identifier)
             .original_result()
     }
 
-    pub fn lottery_whitelist<
+    /// The jackpot is left to any lottery with the name once this timestamp has passed. 
+    pub fn rollover_pool_reserved_until<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
         lottery_name: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        token_identifier: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLotteryWhitelist")
+            .raw_call("getRolloverPoolReservedUntil")
             .argument(&lottery_name)
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    pub fn nft_prizes<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        lottery_name: Arg0,
+        rank: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftPrizes")
+            .argument(&lottery_name)
+            .argument(&rank)
+            .original_result()
+    }
+
+    pub fn set_max_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxDuration")
+            .argument(&max_duration)
+            .original_result()
+    }
+
+    pub fn set_max_tickets<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_tickets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxTickets")
+            .argument(&max_tickets)
+            .original_result()
+    }
+
+    /// As long as no token has been added, lotteries can be started in any token. 
+    pub fn add_allowed_ticket_tokens<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        tokens: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAllowedTicketTokens")
+            .argument(&tokens)
+            .original_result()
+    }
+
+    pub fn remove_allowed_ticket_tokens<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        tokens: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAllowedTicketTokens")
+            .argument(&tokens)
+            .original_result()
+    }
+
+    /// A minimum of 0 removes the limit for the token. 
+    pub fn set_min_ticket_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        min_ticket_price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinTicketPrice")
+            .argument(&token)
+            .argument(&min_ticket_price)
+            .original_result()
+    }
+
+    /// Receives the burn share of the lotteries whose token can no longer be burned by the contract. 
+    pub fn set_fallback_fee_destination<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        destination: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFallbackFeeDestination")
+            .argument(&destination)
+            .original_result()
+    }
+
+    pub fn get_max_duration(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxDuration")
+            .original_result()
+    }
+
+    pub fn get_max_tickets(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxTickets")
+            .original_result()
+    }
+
+    /// Defaults to the owner of the contract. 
+    pub fn get_fallback_fee_destination(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFallbackFeeDestination")
+            .original_result()
+    }
+
+    pub fn allowed_ticket_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowedTicketTokens")
+            .original_result()
+    }
+
+    pub fn min_ticket_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinTicketPrice")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn pause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
             .original_result()
     }
+
+    pub fn paused_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum KeeperReward<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Percentage(BigUint<Api>),
+    FixedAmount(BigUint<Api>),
 }
 
 #[type_abi]
@@ -220,6 +1274,34 @@ pub enum Status {
     Inactive,
     Running,
     Ended,
+    Failed,
+    Cancelled,
+    Pending,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct LotteryResults<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub round: usize,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub winners: ManagedVec<Api, WinnerInfo<Api>>,
+    pub burn_amount: BigUint<Api>,
+    pub draw_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct WinnerInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub rank: usize,
+    pub ticket_id: usize,
+    pub winner: ManagedAddress<Api>,
+    pub prize: BigUint<Api>,
 }
 
 #[type_abi]
@@ -236,3 +1318,22 @@ where
     pub prize_distribution: ManagedVec<Api, u8>,
     pub prize_pool: BigUint<Api>,
 }
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TicketTier<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ticket_price: BigUint<Api>,
+    pub supply: usize,
+    pub tickets_left: usize,
+    pub weight: usize,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct RecurrenceConfig {
+    pub total_tickets: usize,
+    pub duration: u64,
+}
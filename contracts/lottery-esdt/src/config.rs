@@ -0,0 +1,114 @@
+use multiversx_sc::imports::*;
+
+pub const THIRTY_DAYS_IN_SECONDS: u64 = 60 * 60 * 24 * 30;
+pub const MAX_TICKETS: usize = 1_000_000;
+
+/// Limits checked when a lottery is started, and the other contract-wide settings of the owner.
+/// Lotteries that are already running keep the limits they were started with.
+#[multiversx_sc::module]
+pub trait ConfigModule {
+    #[only_owner]
+    #[endpoint(setMaxDuration)]
+    fn set_max_duration(&self, max_duration: u64) {
+        require!(max_duration > 0, "Max duration must be higher than 0!");
+
+        self.max_duration().set(max_duration);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxTickets)]
+    fn set_max_tickets(&self, max_tickets: usize) {
+        require!(max_tickets > 0, "Max tickets must be higher than 0!");
+
+        self.max_tickets().set(max_tickets);
+    }
+
+    /// As long as no token has been added, lotteries can be started in any token.
+    #[only_owner]
+    #[endpoint(addAllowedTicketTokens)]
+    fn add_allowed_ticket_tokens(&self, tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let mut allowed_tokens_mapper = self.allowed_ticket_tokens();
+        for token in tokens {
+            require!(token.is_valid(), "Invalid token name provided!");
+            allowed_tokens_mapper.insert(token);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeAllowedTicketTokens)]
+    fn remove_allowed_ticket_tokens(&self, tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
+        let mut allowed_tokens_mapper = self.allowed_ticket_tokens();
+        for token in tokens {
+            allowed_tokens_mapper.swap_remove(&token);
+        }
+    }
+
+    /// A minimum of 0 removes the limit for the token.
+    #[only_owner]
+    #[endpoint(setMinTicketPrice)]
+    fn set_min_ticket_price(&self, token: EgldOrEsdtTokenIdentifier, min_ticket_price: BigUint) {
+        require!(token.is_valid(), "Invalid token name provided!");
+
+        self.min_ticket_price(&token).set(min_ticket_price);
+    }
+
+    /// Receives the burn share of the lotteries whose token can no longer be burned by the contract.
+    #[only_owner]
+    #[endpoint(setFallbackFeeDestination)]
+    fn set_fallback_fee_destination(&self, destination: ManagedAddress) {
+        self.fallback_fee_destination().set(destination);
+    }
+
+    #[view(getMaxDuration)]
+    fn get_max_duration(&self) -> u64 {
+        let max_duration_mapper = self.max_duration();
+        if max_duration_mapper.is_empty() {
+            return THIRTY_DAYS_IN_SECONDS;
+        }
+
+        max_duration_mapper.get()
+    }
+
+    #[view(getMaxTickets)]
+    fn get_max_tickets(&self) -> usize {
+        let max_tickets_mapper = self.max_tickets();
+        if max_tickets_mapper.is_empty() {
+            return MAX_TICKETS;
+        }
+
+        max_tickets_mapper.get()
+    }
+
+    /// Defaults to the owner of the contract.
+    #[view(getFallbackFeeDestination)]
+    fn get_fallback_fee_destination(&self) -> ManagedAddress {
+        let fallback_mapper = self.fallback_fee_destination();
+        if fallback_mapper.is_empty() {
+            return self.blockchain().get_owner_address();
+        }
+
+        fallback_mapper.get()
+    }
+
+    fn is_ticket_token_allowed(&self, token: &EgldOrEsdtTokenIdentifier) -> bool {
+        let allowed_tokens_mapper = self.allowed_ticket_tokens();
+        allowed_tokens_mapper.is_empty() || allowed_tokens_mapper.contains(token)
+    }
+
+    #[storage_mapper("maxDuration")]
+    fn max_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("maxTickets")]
+    fn max_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("fallbackFeeDestination")]
+    fn fallback_fee_destination(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAllowedTicketTokens)]
+    #[storage_mapper("allowedTicketTokens")]
+    fn allowed_ticket_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getMinTicketPrice)]
+    #[storage_mapper("minTicketPrice")]
+    fn min_ticket_price(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,157 @@
+use multiversx_sc::types::{EsdtLocalRole, ManagedByteArray, ManagedVec};
+
+use crate::lottery_info::{LotteryInfo, RecurrenceConfig};
+use crate::HASH_LENGTH;
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    /// Only the number of whitelisted addresses is logged, the addresses themselves can be queried from the views.
+    #[event("lotteryStarted")]
+    fn lottery_started_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] burn_basis_points: &BigUint,
+        #[indexed] creator_fee_percentage: &BigUint,
+        #[indexed] prize_distribution_basis_points: &ManagedVec<Self::Api, u16>,
+        #[indexed] whitelist_size: usize,
+        #[indexed] recurrence: &Option<RecurrenceConfig>,
+        info: &LotteryInfo<Self::Api>,
+    );
+
+    #[event("whitelistMerkleRootSet")]
+    fn whitelist_merkle_root_set_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] merkle_root: &ManagedByteArray<Self::Api, HASH_LENGTH>,
+    );
+
+    /// The tickets of a purchase are always consecutive, so only the first and last ids are logged.
+    #[event("ticketBought")]
+    fn ticket_bought_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] ticket_count: usize,
+        #[indexed] first_ticket_id: usize,
+        #[indexed] last_ticket_id: usize,
+        #[indexed] tier: usize,
+    );
+
+    #[event("lotterySponsored")]
+    fn lottery_sponsored_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] sponsor: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the prize is added to the claimable balance of the winner. Prizes of 0 are not logged.
+    #[event("prizePaid")]
+    fn prize_paid_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] rank: usize,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("nftPrizePaid")]
+    fn nft_prize_paid_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] rank: usize,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] token_nonce: u64,
+        amount: &BigUint,
+    );
+
+    #[event("keeperRewardPaid")]
+    fn keeper_reward_paid_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("jackpotRolledOver")]
+    fn jackpot_rolled_over_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("tokensBurned")]
+    fn tokens_burned_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] token_identifier: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("deadlineExtended")]
+    fn deadline_extended_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] deadline: u64,
+    );
+
+    /// The tickets left include the ones just added.
+    #[event("ticketSupplyIncreased")]
+    fn ticket_supply_increased_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] additional_tickets: usize,
+        #[indexed] tickets_left: usize,
+    );
+
+    /// Emitted whether or not tickets were sold, before the lottery is cleared or opened to refunds.
+    #[event("lotteryCancelled")]
+    fn lottery_cancelled_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("lotteryPaused")]
+    fn lottery_paused_event(&self, #[indexed] lottery_name: &ManagedBuffer);
+
+    #[event("lotteryUnpaused")]
+    fn lottery_unpaused_event(&self, #[indexed] lottery_name: &ManagedBuffer);
+
+    /// Emitted by the callback of `set_roles`, once the role has been granted to the contract.
+    #[event("rolesGranted")]
+    fn roles_granted_event(
+        &self,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] role: EsdtLocalRole,
+    );
+
+    /// Emitted by the callback of `set_roles` when the system smart contract refused to grant the role.
+    #[event("rolesGrantFailed")]
+    fn roles_grant_failed_event(
+        &self,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] role: EsdtLocalRole,
+        #[indexed] error_code: u32,
+        error_message: &ManagedBuffer,
+    );
+
+    /// Emitted when the burn share goes to the fee destination of the lottery,
+    /// or to the fallback destination if the contract can no longer burn the token.
+    #[event("burnRedirected")]
+    fn burn_redirected_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] destination: &ManagedAddress,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("lotteryCleared")]
+    fn lottery_cleared_event(&self, #[indexed] lottery_name: &ManagedBuffer);
+}
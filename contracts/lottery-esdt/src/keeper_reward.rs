@@ -0,0 +1,12 @@
+use multiversx_sc::{api::ManagedTypeApi, types::BigUint};
+
+use multiversx_sc::derive_imports::*;
+
+/// The part of the prize pool paid to the caller of `determine_winner`,
+/// as a percentage of the pool left after the burn and the creator fee, or as a fixed amount.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub enum KeeperReward<M: ManagedTypeApi> {
+    Percentage(BigUint<M>),
+    FixedAmount(BigUint<M>),
+}
@@ -1,22 +1,42 @@
 #![no_std]
 
 use multiversx_sc::imports::*;
+use multiversx_sc::storage::StorageKey;
 
+pub mod config;
+pub mod events;
+mod keeper_reward;
 mod lottery_info;
+mod lottery_results;
 mod status;
+mod ticket_range;
+mod ticket_tier;
 
-use lottery_info::LotteryInfo;
+use config::{MAX_TICKETS, THIRTY_DAYS_IN_SECONDS};
+use keeper_reward::KeeperReward;
+use lottery_info::{LotteryInfo, RecurrenceConfig};
+use lottery_results::{LotteryResults, WinnerInfo};
 use status::Status;
+use ticket_range::TicketRange;
+use ticket_tier::TicketTier;
 
 const PERCENTAGE_TOTAL: u32 = 100;
-const THIRTY_DAYS_IN_SECONDS: u64 = 60 * 60 * 24 * 30;
-const MAX_TICKETS: usize = 800;
+const BASIS_POINTS_TOTAL: u32 = 10_000;
+const BASIS_POINTS_PER_PERCENT: u32 = BASIS_POINTS_TOTAL / PERCENTAGE_TOTAL;
+const BASE_TIER: usize = 0;
+const HASH_LENGTH: usize = 32;
+const MAX_UNIQUE_WINNER_DRAWS: usize = 20;
+/// Any non-empty value marks a key of a `ManagedMap` as present.
+const WON: &[u8] = &[1];
 
 #[multiversx_sc::contract]
-pub trait Lottery {
+pub trait Lottery:
+    config::ConfigModule + events::EventsModule + multiversx_sc_modules::pause::PauseModule
+{
     #[init]
     fn init(&self) {}
 
+    #[allow_multiple_var_args]
     #[endpoint]
     fn start(
         &self,
@@ -29,6 +49,11 @@ pub trait Lottery {
         opt_prize_distribution: ManagedOption<ManagedVec<u8>>,
         opt_whitelist: ManagedOption<ManagedVec<ManagedAddress>>,
         opt_burn_percentage: OptionalValue<BigUint>,
+        opt_min_tickets: OptionalValue<usize>,
+        opt_creator_fee_percentage: OptionalValue<BigUint>,
+        opt_recurring: OptionalValue<bool>,
+        opt_start_time: OptionalValue<u64>,
+        opt_fee_destination: OptionalValue<ManagedAddress>,
     ) {
         self.start_lottery(
             lottery_name,
@@ -37,12 +62,19 @@ pub trait Lottery {
             opt_total_tickets,
             opt_deadline,
             opt_max_entries_per_user,
-            opt_prize_distribution,
+            self.widen_prize_distribution(opt_prize_distribution),
             opt_whitelist,
             opt_burn_percentage,
+            opt_min_tickets,
+            opt_creator_fee_percentage,
+            opt_recurring,
+            opt_start_time,
+            opt_fee_destination,
+            false,
         );
     }
 
+    #[allow_multiple_var_args]
     #[endpoint(createLotteryPool)]
     fn create_lottery_pool(
         &self,
@@ -55,6 +87,11 @@ pub trait Lottery {
         opt_prize_distribution: ManagedOption<ManagedVec<u8>>,
         opt_whitelist: ManagedOption<ManagedVec<ManagedAddress>>,
         opt_burn_percentage: OptionalValue<BigUint>,
+        opt_min_tickets: OptionalValue<usize>,
+        opt_creator_fee_percentage: OptionalValue<BigUint>,
+        opt_recurring: OptionalValue<bool>,
+        opt_start_time: OptionalValue<u64>,
+        opt_fee_destination: OptionalValue<ManagedAddress>,
     ) {
         self.start_lottery(
             lottery_name,
@@ -63,12 +100,60 @@ pub trait Lottery {
             opt_total_tickets,
             opt_deadline,
             opt_max_entries_per_user,
-            opt_prize_distribution,
+            self.widen_prize_distribution(opt_prize_distribution),
             opt_whitelist,
             opt_burn_percentage,
+            opt_min_tickets,
+            opt_creator_fee_percentage,
+            opt_recurring,
+            opt_start_time,
+            opt_fee_destination,
+            false,
+        );
+    }
+
+    /// Same as `start`, but the prize distribution and the burn are expressed in basis points,
+    /// where 10_000 is 100%.
+    #[allow_multiple_var_args]
+    #[endpoint(startWithBasisPoints)]
+    fn start_with_basis_points(
+        &self,
+        lottery_name: ManagedBuffer,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        ticket_price: BigUint,
+        opt_total_tickets: Option<usize>,
+        opt_deadline: Option<u64>,
+        opt_max_entries_per_user: Option<usize>,
+        opt_prize_distribution: ManagedOption<ManagedVec<u16>>,
+        opt_whitelist: ManagedOption<ManagedVec<ManagedAddress>>,
+        opt_burn_basis_points: OptionalValue<BigUint>,
+        opt_min_tickets: OptionalValue<usize>,
+        opt_creator_fee_percentage: OptionalValue<BigUint>,
+        opt_recurring: OptionalValue<bool>,
+        opt_start_time: OptionalValue<u64>,
+        opt_fee_destination: OptionalValue<ManagedAddress>,
+    ) {
+        self.start_lottery(
+            lottery_name,
+            token_identifier,
+            ticket_price,
+            opt_total_tickets,
+            opt_deadline,
+            opt_max_entries_per_user,
+            opt_prize_distribution,
+            opt_whitelist,
+            opt_burn_basis_points,
+            opt_min_tickets,
+            opt_creator_fee_percentage,
+            opt_recurring,
+            opt_start_time,
+            opt_fee_destination,
+            true,
         );
     }
 
+    /// The prize distribution and the burn are either percentages, or basis points if `in_basis_points` is set.
+    /// A lottery with a start time in the future is announced right away, but only sells tickets from that time on.
     #[allow(clippy::too_many_arguments)]
     fn start_lottery(
         &self,
@@ -78,18 +163,38 @@ pub trait Lottery {
         opt_total_tickets: Option<usize>,
         opt_deadline: Option<u64>,
         opt_max_entries_per_user: Option<usize>,
-        opt_prize_distribution: ManagedOption<ManagedVec<u8>>,
+        opt_prize_distribution: ManagedOption<ManagedVec<u16>>,
         opt_whitelist: ManagedOption<ManagedVec<ManagedAddress>>,
         opt_burn_percentage: OptionalValue<BigUint>,
+        opt_min_tickets: OptionalValue<usize>,
+        opt_creator_fee_percentage: OptionalValue<BigUint>,
+        opt_recurring: OptionalValue<bool>,
+        opt_start_time: OptionalValue<u64>,
+        opt_fee_destination: OptionalValue<ManagedAddress>,
+        in_basis_points: bool,
     ) {
+        self.require_not_paused();
         require!(!lottery_name.is_empty(), "Name can't be empty!");
 
         let timestamp = self.blockchain().get_block_timestamp();
-        let total_tickets = opt_total_tickets.unwrap_or(MAX_TICKETS);
-        let deadline = opt_deadline.unwrap_or(timestamp + THIRTY_DAYS_IN_SECONDS);
-        let max_entries_per_user = opt_max_entries_per_user.unwrap_or(MAX_TICKETS);
+        let max_tickets = self.get_max_tickets();
+        let max_deadline = timestamp + self.get_max_duration();
+        let total_tickets = opt_total_tickets.unwrap_or(max_tickets);
+        let deadline = opt_deadline.unwrap_or(max_deadline);
+        let max_entries_per_user = opt_max_entries_per_user.unwrap_or(max_tickets);
+        let percentage_total = if in_basis_points {
+            BASIS_POINTS_TOTAL
+        } else {
+            PERCENTAGE_TOTAL
+        };
         let prize_distribution = opt_prize_distribution
-            .unwrap_or_else(|| ManagedVec::from_single_item(PERCENTAGE_TOTAL as u8));
+            .unwrap_or_else(|| ManagedVec::from_single_item(percentage_total as u16));
+        let min_tickets = opt_min_tickets.into_option().unwrap_or_default();
+        // a start time of 0 only acts as a placeholder for the arguments that follow it
+        let start_time = match opt_start_time {
+            OptionalValue::Some(start_time) if start_time > 0 => start_time,
+            _ => timestamp,
+        };
 
         require!(
             self.status(&lottery_name) == Status::Inactive,
@@ -97,51 +202,115 @@ pub trait Lottery {
         );
         require!(!lottery_name.is_empty(), "Can't have empty lottery name!");
         require!(token_identifier.is_valid(), "Invalid token name provided!");
+        require!(
+            self.is_ticket_token_allowed(&token_identifier),
+            "Ticket token is not allowed!"
+        );
         require!(ticket_price > 0, "Ticket price must be higher than 0!");
+        require!(
+            ticket_price >= self.min_ticket_price(&token_identifier).get(),
+            "Ticket price is lower than the minimum!"
+        );
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.can_win_jackpot(&lottery_name, &token_identifier, &caller),
+            "The jackpot of this lottery name belongs to another creator!"
+        );
         require!(
             total_tickets > 0,
             "Must have more than 0 tickets available!"
         );
         require!(
-            total_tickets <= MAX_TICKETS,
-            "Only 800 or less total tickets per lottery are allowed!"
+            total_tickets <= max_tickets,
+            "Total tickets can't exceed the maximum per lottery!"
+        );
+        require!(
+            min_tickets <= total_tickets,
+            "Minimum tickets can't be higher than the total tickets!"
         );
+        require!(start_time >= timestamp, "Start time can't be in the past!");
         require!(deadline > timestamp, "Deadline can't be in the past!");
         require!(
-            deadline <= timestamp + THIRTY_DAYS_IN_SECONDS,
-            "Deadline can't be later than 30 days from now!"
+            deadline > start_time,
+            "Deadline must be after the start time!"
         );
         require!(
-            max_entries_per_user > 0,
-            "Must have more than 0 max entries per user!"
+            deadline <= max_deadline,
+            "Deadline can't be later than the maximum duration from now!"
         );
         require!(
-            self.sum_array(&prize_distribution) == PERCENTAGE_TOTAL,
-            "Prize distribution must add up to exactly 100(%)!"
+            max_entries_per_user > 0,
+            "Must have more than 0 max entries per user!"
         );
+        if in_basis_points {
+            require!(
+                self.sum_array(&prize_distribution) == BASIS_POINTS_TOTAL,
+                "Prize distribution must add up to exactly 10000 basis points!"
+            );
+        } else {
+            require!(
+                self.sum_array(&prize_distribution) == PERCENTAGE_TOTAL,
+                "Prize distribution must add up to exactly 100(%)!"
+            );
+        }
 
+        // a burn percentage of 0 only acts as a placeholder for the arguments that follow it
+        let opt_fee_destination = opt_fee_destination.into_option();
         match opt_burn_percentage {
-            OptionalValue::Some(burn_percentage) => {
-                require!(!token_identifier.is_egld(), "EGLD can't be burned!");
+            OptionalValue::Some(burn_percentage) if burn_percentage > 0 => {
+                if let Some(fee_destination) = &opt_fee_destination {
+                    // the burn share is sent instead, so it works for any token
+                    self.fee_destination(&lottery_name).set(fee_destination);
+                } else {
+                    self.require_burn_role(&token_identifier);
+                }
 
-                let roles = self
-                    .blockchain()
-                    .get_esdt_local_roles(&token_identifier.clone().unwrap_esdt());
                 require!(
-                    roles.has_role(&EsdtLocalRole::Burn),
-                    "The contract can't burn the selected token!"
-                );
-
-                require!(
-                    burn_percentage < PERCENTAGE_TOTAL,
+                    burn_percentage < percentage_total,
                     "Invalid burn percentage!"
                 );
-                self.burn_percentage_for_lottery(&lottery_name)
-                    .set(burn_percentage);
+                if in_basis_points {
+                    self.burn_basis_points_for_lottery(&lottery_name)
+                        .set(burn_percentage);
+                } else {
+                    self.burn_percentage_for_lottery(&lottery_name)
+                        .set(burn_percentage);
+                }
             }
-            OptionalValue::None => {}
+            _ => require!(
+                opt_fee_destination.is_none(),
+                "A fee destination needs a burn percentage!"
+            ),
+        }
+
+        let creator_fee_percentage = opt_creator_fee_percentage.into_option().unwrap_or_default();
+        if creator_fee_percentage > 0 {
+            let burn_basis_points = self.get_burn_basis_points(&lottery_name);
+            require!(
+                burn_basis_points + &creator_fee_percentage * BASIS_POINTS_PER_PERCENT
+                    < BASIS_POINTS_TOTAL,
+                "Invalid creator fee percentage!"
+            );
+            self.creator_fee_percentage_for_lottery(&lottery_name)
+                .set(creator_fee_percentage);
+        }
+
+        if opt_recurring.into_option().unwrap_or_default() {
+            self.lottery_recurrence(&lottery_name)
+                .set(RecurrenceConfig {
+                    total_tickets,
+                    duration: deadline - start_time,
+                });
         }
 
+        self.lottery_start_time(&lottery_name).set(start_time);
+
+        if min_tickets > 0 {
+            self.min_tickets(&lottery_name).set(min_tickets);
+        }
+
+        self.lottery_creator(&lottery_name).set(&caller);
+
         if let Some(whitelist) = opt_whitelist.as_option() {
             let mut mapper = self.lottery_whitelist(&lottery_name);
             for addr in &*whitelist {
@@ -149,248 +318,2169 @@ pub trait Lottery {
             }
         }
 
+        // the info keeps the layout it had before the upgrade, so the lotteries stored then still decode;
+        // a distribution in basis points and the other options added since are kept apart from it
+        let mut percentage_distribution = ManagedVec::new();
+        if in_basis_points {
+            self.prize_distribution_basis_points(&lottery_name)
+                .set(&prize_distribution);
+        } else {
+            for percentage in &prize_distribution {
+                percentage_distribution.push(percentage as u8);
+            }
+        }
+
         let info = LotteryInfo {
             token_identifier,
             ticket_price,
             tickets_left: total_tickets,
             deadline,
             max_entries_per_user,
-            prize_distribution,
+            prize_distribution: percentage_distribution,
             prize_pool: BigUint::zero(),
         };
 
         self.lottery_info(&lottery_name).set(&info);
+        self.round(&lottery_name)
+            .set(self.lottery_rounds(&lottery_name).get() + 1);
+        self.emit_lottery_started_event(&lottery_name, &caller, &info);
     }
 
+    /// The number of tickets is derived from the payment, which must be a multiple of the ticket price.
+    /// If more tickets are requested than can be bought, the purchase is rejected,
+    /// unless `opt_partial_fill` is set, in which case the available tickets are bought
+    /// and the rest of the payment is sent back.
+    /// `opt_tier` selects one of the ticket tiers of the lottery, the default tier 0 being the lottery's own ticket.
+    /// `opt_merkle_proof` proves that the caller is on the allow-list of a lottery with a Merkle root set.
+    /// Lotteries with a required collection also expect a token of that collection as second payment,
+    /// which is sent back right away.
+    #[allow_multiple_var_args]
     #[endpoint]
     #[payable("*")]
-    fn buy_ticket(&self, lottery_name: ManagedBuffer) {
-        let (token_identifier, payment) = self.call_value().egld_or_single_fungible_esdt();
+    fn buy_ticket(
+        &self,
+        lottery_name: ManagedBuffer,
+        opt_partial_fill: OptionalValue<bool>,
+        opt_tier: OptionalValue<usize>,
+        opt_merkle_proof: OptionalValue<ManagedVec<ManagedByteArray<Self::Api, HASH_LENGTH>>>,
+    ) {
+        self.require_lottery_not_paused(&lottery_name);
+        let (token_identifier, payment) = if self.required_collection(&lottery_name).is_empty() {
+            self.call_value().egld_or_single_fungible_esdt()
+        } else {
+            self.split_token_gated_payment(&lottery_name)
+        };
+        let partial_fill = opt_partial_fill.into_option().unwrap_or_default();
+        let tier = opt_tier.into_option().unwrap_or(BASE_TIER);
 
         match self.status(&lottery_name) {
             Status::Inactive => sc_panic!("Lottery is currently inactive."),
-            Status::Running => {
-                self.update_after_buy_ticket(&lottery_name, &token_identifier, &payment)
-            }
+            Status::Pending => sc_panic!("Lottery has not opened yet!"),
+            Status::Running => self.update_after_buy_ticket(
+                &lottery_name,
+                &token_identifier,
+                &payment,
+                partial_fill,
+                tier,
+                opt_merkle_proof.into_option(),
+            ),
             Status::Ended => {
                 sc_panic!("Lottery entry period has ended! Awaiting winner announcement.")
             }
+            Status::Failed => sc_panic!("Lottery entry period has ended! Refunds are available."),
+            Status::Cancelled => sc_panic!("Lottery has been cancelled! Refunds are available."),
         };
     }
 
     #[endpoint]
     fn determine_winner(&self, lottery_name: ManagedBuffer) {
+        self.require_lottery_not_paused(&lottery_name);
         match self.status(&lottery_name) {
             Status::Inactive => sc_panic!("Lottery is inactive!"),
+            Status::Pending => sc_panic!("Lottery has not opened yet!"),
             Status::Running => sc_panic!("Lottery is still running!"),
             Status::Ended => {
-                self.distribute_prizes(&lottery_name);
-                self.clear_storage(&lottery_name);
+                self.migrate_legacy_tickets(&lottery_name);
+                let results = self.distribute_prizes(&lottery_name);
+                self.archive_results(&lottery_name, results);
+
+                if self.lottery_recurrence(&lottery_name).is_empty() {
+                    self.clear_storage(&lottery_name);
+                } else {
+                    self.start_next_round(&lottery_name);
+                }
             }
+            Status::Failed => {
+                sc_panic!("Lottery did not sell enough tickets! Refunds are available.")
+            }
+            Status::Cancelled => sc_panic!("Lottery has been cancelled! Refunds are available."),
         };
     }
 
-    #[view]
-    fn status(&self, lottery_name: &ManagedBuffer) -> Status {
-        if self.lottery_info(lottery_name).is_empty() {
-            return Status::Inactive;
-        }
+    #[endpoint(claimPrize)]
+    fn claim_prize(&self, lottery_name: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        let mut prizes_mapper = self.claimable_prizes(&caller, &lottery_name);
+        require!(!prizes_mapper.is_empty(), "No prizes to claim!");
 
-        let info = self.lottery_info(lottery_name).get();
-        let current_time = self.blockchain().get_block_timestamp();
-        if current_time > info.deadline || info.tickets_left == 0 {
-            return Status::Ended;
+        for prize in prizes_mapper.iter() {
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&prize.token_identifier, prize.token_nonce, &prize.amount)
+                .transfer();
         }
 
-        Status::Running
+        prizes_mapper.clear();
+        self.claimable_lotteries(&caller).swap_remove(&lottery_name);
     }
 
-    fn update_after_buy_ticket(
-        &self,
-        lottery_name: &ManagedBuffer,
-        token_identifier: &EgldOrEsdtTokenIdentifier,
-        payment: &BigUint,
-    ) {
-        let info_mapper = self.lottery_info(lottery_name);
-        let mut info = info_mapper.get();
-        let caller = self.blockchain().get_caller();
-        let whitelist = self.lottery_whitelist(lottery_name);
+    /// Adds the payment to the prizes of a running lottery. Anyone can sponsor a lottery, in the lottery's token.
+    /// Sponsorships are kept apart from the ticket revenue, which is the only base of the creator fee.
+    /// They are sent back to the sponsors if the lottery is drawn without tickets, cancelled or failed.
+    #[payable("*")]
+    #[endpoint(sponsorLottery)]
+    fn sponsor_lottery(&self, lottery_name: ManagedBuffer) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_not_paused(&lottery_name);
 
+        let (token_identifier, amount) = self.call_value().egld_or_single_fungible_esdt();
         require!(
-            whitelist.is_empty() || whitelist.contains(&caller),
-            "You are not allowed to participate in this lottery!"
+            token_identifier == self.lottery_info(&lottery_name).get().token_identifier
+                && amount > 0,
+            "Wrong sponsorship token!"
         );
+
+        let sponsor = self.blockchain().get_caller();
+        self.sponsored_prize_pool(&lottery_name)
+            .update(|sponsored_prize_pool| *sponsored_prize_pool += &amount);
+        let mut sponsorships = self.sponsorships(&lottery_name);
+        let sponsorship = sponsorships.get(&sponsor).unwrap_or_default() + &amount;
+        sponsorships.insert(sponsor.clone(), sponsorship);
+
+        self.lottery_sponsored_event(&lottery_name, &sponsor, &amount);
+    }
+
+    /// Sets whether the burn percentage of the lottery also applies to its sponsorships.
+    /// By default, sponsorships are burned like the ticket revenue.
+    #[endpoint(excludeSponsorshipsFromBurn)]
+    fn exclude_sponsorships_from_burn(&self, lottery_name: ManagedBuffer, excluded: bool) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+
+        self.sponsorships_excluded_from_burn(&lottery_name)
+            .set(excluded);
+    }
+
+    /// Turns on the rollover mode of a running lottery. The prize pool of a draw without tickets,
+    /// and `rollover_percentage` of the prize pool of every other draw, go to a jackpot kept under the lottery's name.
+    /// The jackpot is added to the prizes of the next draw with tickets of a lottery with the same name and token.
+    #[endpoint(enableRollover)]
+    fn enable_rollover(&self, lottery_name: ManagedBuffer, rollover_percentage: BigUint) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
         require!(
-            token_identifier == &info.token_identifier && payment == &info.ticket_price,
-            "Wrong ticket fee!"
+            rollover_percentage < PERCENTAGE_TOTAL,
+            "Invalid rollover percentage!"
         );
 
-        let entries_mapper = self.number_of_entries_for_user(lottery_name, &caller);
-        let mut entries = entries_mapper.get();
+        self.rollover_enabled(&lottery_name).set(true);
+        self.rollover_percentage_for_lottery(&lottery_name)
+            .set(rollover_percentage);
+    }
+
+    /// Makes every prize rank go to a different address, before any ticket is sold.
+    /// If there are less ticket holders than prize ranks, the shares of the ranks left empty go to the 1st place.
+    #[endpoint(enableUniqueWinners)]
+    fn enable_unique_winners(&self, lottery_name: ManagedBuffer) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
         require!(
-            entries < info.max_entries_per_user,
-            "Ticket limit exceeded for this lottery!"
+            self.tickets_sold(&lottery_name) == 0,
+            "Unique winners can only be enabled before any ticket is sold!"
         );
 
-        self.ticket_holders(lottery_name).push(&caller);
+        self.unique_winners(&lottery_name).set(true);
+    }
 
-        entries += 1;
-        info.tickets_left -= 1;
-        info.prize_pool += &info.ticket_price;
+    /// Sets the reward of whoever successfully calls `determine_winner`, before any ticket is sold.
+    /// The reward is taken from the prize pool of each draw with tickets, after the burn and the creator fee,
+    /// and is added to the claimable prizes of the caller. A fixed amount is capped at the prize pool.
+    #[endpoint(setKeeperReward)]
+    fn set_keeper_reward(
+        &self,
+        lottery_name: ManagedBuffer,
+        keeper_reward: KeeperReward<Self::Api>,
+    ) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(
+            self.tickets_sold(&lottery_name) == 0,
+            "The keeper reward can only be set before any ticket is sold!"
+        );
+        match &keeper_reward {
+            KeeperReward::Percentage(percentage) => require!(
+                *percentage > 0 && *percentage < PERCENTAGE_TOTAL,
+                "Invalid keeper reward percentage!"
+            ),
+            KeeperReward::FixedAmount(amount) => {
+                require!(*amount > 0, "Keeper reward must be higher than 0!")
+            }
+        }
 
-        entries_mapper.set(entries);
-        info_mapper.set(&info);
+        self.keeper_reward(&lottery_name).set(keeper_reward);
     }
 
-    fn distribute_prizes(&self, lottery_name: &ManagedBuffer) {
-        let mut info = self.lottery_info(lottery_name).get();
-        let ticket_holders_mapper = self.ticket_holders(lottery_name);
-        let total_tickets = ticket_holders_mapper.len();
-
-        if total_tickets == 0 {
-            return;
+    /// Returns the amount given by each sponsor of a lottery.
+    #[view(getSponsorships)]
+    fn get_sponsorships(
+        &self,
+        lottery_name: ManagedBuffer,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut sponsorships = MultiValueEncoded::new();
+        for (sponsor, amount) in self.sponsorships(&lottery_name).iter() {
+            sponsorships.push((sponsor, amount).into());
         }
 
-        let burn_percentage = self.burn_percentage_for_lottery(lottery_name).get();
-        if burn_percentage > 0 {
-            let burn_amount = self.calculate_percentage_of(&info.prize_pool, &burn_percentage);
+        sponsorships
+    }
 
-            // Prevent crashing if the role was unset while the lottery was running
-            // The tokens will simply remain locked forever
-            let esdt_token_id = info.token_identifier.clone().unwrap_esdt();
-            let roles = self.blockchain().get_esdt_local_roles(&esdt_token_id);
-            if roles.has_role(&EsdtLocalRole::Burn) {
-                self.send().esdt_local_burn(&esdt_token_id, 0, &burn_amount);
-            }
+    /// Escrows an NFT or SFT as an extra prize for the given rank, counted from 1.
+    /// Deposits are only accepted before any ticket is sold, so buyers know every prize up front.
+    /// Prizes that nobody wins are given back to the creator.
+    #[payable("*")]
+    #[endpoint(depositNftPrize)]
+    fn deposit_nft_prize(&self, lottery_name: ManagedBuffer, rank: usize) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(
+            self.tickets_sold(&lottery_name) == 0,
+            "Prizes can only be deposited before any ticket is sold!"
+        );
+        require!(
+            rank > 0 && rank <= self.number_of_prizes(&lottery_name),
+            "Invalid prize rank!"
+        );
 
-            info.prize_pool -= burn_amount;
-        }
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_nonce > 0,
+            "Only NFTs and SFTs can be deposited as prizes!"
+        );
 
-        // if there are less tickets than the distributed prize pool,
-        // the 1st place gets the leftover, maybe could split between the remaining
-        // but this is a rare case anyway and it's not worth the overhead
-        let total_winning_tickets = if total_tickets < info.prize_distribution.len() {
-            total_tickets
-        } else {
-            info.prize_distribution.len()
-        };
-        let total_prize = info.prize_pool.clone();
-        let winning_tickets = self.get_distinct_random(1, total_tickets, total_winning_tickets);
+        self.nft_prizes(&lottery_name, rank).push(&payment);
+    }
 
-        // distribute to the first place last. Laws of probability say that order doesn't matter.
-        // this is done to mitigate the effects of BigUint division leading to "spare" prize money being left out at times
-        // 1st place will get the spare money instead.
-        for i in (1..total_winning_tickets).rev() {
-            let winning_ticket_id = winning_tickets[i];
-            let winner_address = ticket_holders_mapper.get(winning_ticket_id);
-            let prize = self.calculate_percentage_of(
-                &total_prize,
-                &BigUint::from(info.prize_distribution.get(i)),
-            );
+    /// Adds a ticket tier to a running lottery, before any ticket is sold. Returns the id of the new tier.
+    /// Tier tickets are paid in the token of the lottery and also count towards its total tickets.
+    #[endpoint(addTicketTier)]
+    fn add_ticket_tier(
+        &self,
+        lottery_name: ManagedBuffer,
+        ticket_price: BigUint,
+        supply: usize,
+        weight: usize,
+    ) -> usize {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(
+            self.tickets_sold(&lottery_name) == 0,
+            "Ticket tiers can only be added before any ticket is sold!"
+        );
+        require!(ticket_price > 0, "Ticket price must be higher than 0!");
+        let token_identifier = self.lottery_info(&lottery_name).get().token_identifier;
+        require!(
+            ticket_price >= self.min_ticket_price(&token_identifier).get(),
+            "Ticket price is lower than the minimum!"
+        );
+        require!(supply > 0, "Tier supply must be higher than 0!");
+        require!(weight > 0, "Tier weight must be higher than 0!");
+        require!(
+            weight <= MAX_TICKETS,
+            "Tier weight can't exceed the maximum tickets per lottery!"
+        );
 
-            self.tx()
-                .to(winner_address)
-                .egld_or_single_esdt(&info.token_identifier, 0, &prize)
-                .transfer();
-            info.prize_pool -= prize;
-        }
+        self.ticket_tiers(&lottery_name).push(&TicketTier {
+            ticket_price,
+            supply,
+            tickets_left: supply,
+            weight,
+        })
+    }
 
-        // send leftover to first place
-        let first_place_winner = ticket_holders_mapper.get(winning_tickets[0]);
-        self.tx()
-            .to(&first_place_winner)
-            .egld_or_single_esdt(&info.token_identifier, 0, &info.prize_pool)
-            .transfer();
+    /// Restricts a running lottery to the addresses of a Merkle tree, instead of storing each of them.
+    /// The root can only be set or replaced before any ticket is sold.
+    /// The leaves are the keccak256 hashes of the addresses, and each pair of nodes is hashed in ascending order.
+    /// Addresses on the explicit whitelist, if any, are still allowed without a proof.
+    #[endpoint(setWhitelistMerkleRoot)]
+    fn set_whitelist_merkle_root(
+        &self,
+        lottery_name: ManagedBuffer,
+        merkle_root: ManagedByteArray<Self::Api, HASH_LENGTH>,
+    ) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(
+            self.tickets_sold(&lottery_name) == 0,
+            "The whitelist Merkle root can only be set before any ticket is sold!"
+        );
+
+        self.whitelist_merkle_root_set_event(&lottery_name, &merkle_root);
+        self.whitelist_merkle_root(&lottery_name).set(merkle_root);
     }
 
-    fn clear_storage(&self, lottery_name: &ManagedBuffer) {
-        let mut ticket_holders_mapper = self.ticket_holders(lottery_name);
-        let current_ticket_number = ticket_holders_mapper.len();
+    /// Adding to the whitelist of an open lottery restricts it to the added addresses.
+    /// A lottery with a whitelist stays restricted even once every address has been removed from it.
+    #[endpoint(addToWhitelist)]
+    fn add_to_whitelist(
+        &self,
+        lottery_name: ManagedBuffer,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_editable_participant_lists(&lottery_name);
 
-        for i in 1..=current_ticket_number {
-            let addr = ticket_holders_mapper.get(i);
-            self.number_of_entries_for_user(lottery_name, &addr).clear();
+        let mut whitelist = self.lottery_whitelist(&lottery_name);
+        for address in addresses {
+            whitelist.insert(address);
         }
-
-        ticket_holders_mapper.clear();
-        self.lottery_info(lottery_name).clear();
-        self.lottery_whitelist(lottery_name).clear();
-        self.burn_percentage_for_lottery(lottery_name).clear();
     }
 
-    fn sum_array(&self, array: &ManagedVec<u8>) -> u32 {
-        let mut sum = 0;
+    #[endpoint(removeFromWhitelist)]
+    fn remove_from_whitelist(
+        &self,
+        lottery_name: ManagedBuffer,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_editable_participant_lists(&lottery_name);
 
-        for item in array {
-            sum += item as u32;
+        let mut whitelist = self.lottery_whitelist(&lottery_name);
+        if !whitelist.is_empty() {
+            self.whitelist_enabled(&lottery_name).set(true);
+        }
+        for address in addresses {
+            whitelist.swap_remove(&address);
         }
-
-        sum
     }
 
-    /// does not check if max - min >= amount, that is the caller's job
-    fn get_distinct_random(
+    /// Blacklisted addresses can't buy tickets, even if they are whitelisted.
+    /// Tickets they have already bought stay valid.
+    #[endpoint(addToBlacklist)]
+    fn add_to_blacklist(
         &self,
-        min: usize,
-        max: usize,
-        amount: usize,
-    ) -> ArrayVec<usize, MAX_TICKETS> {
-        let mut rand_numbers = ArrayVec::new();
+        lottery_name: ManagedBuffer,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_editable_participant_lists(&lottery_name);
 
-        for num in min..=max {
-            rand_numbers.push(num);
+        let mut blacklist = self.lottery_blacklist(&lottery_name);
+        for address in addresses {
+            blacklist.insert(address);
         }
+    }
 
-        let total_numbers = rand_numbers.len();
-        let mut rand = RandomnessSource::new();
+    #[endpoint(removeFromBlacklist)]
+    fn remove_from_blacklist(
+        &self,
+        lottery_name: ManagedBuffer,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_editable_participant_lists(&lottery_name);
 
-        for i in 0..amount {
-            let rand_index = rand.next_usize_in_range(0, total_numbers);
-            rand_numbers.swap(i, rand_index);
+        let mut blacklist = self.lottery_blacklist(&lottery_name);
+        for address in addresses {
+            blacklist.swap_remove(&address);
         }
+    }
 
-        rand_numbers
+    /// Restricts a running lottery to the holders of an NFT or SFT of the given collection.
+    /// The ticket token of the lottery must be an ESDT, as it is paid in the same transfer as the collection token.
+    #[endpoint(setRequiredCollection)]
+    fn set_required_collection(&self, lottery_name: ManagedBuffer, collection: TokenIdentifier) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(
+            collection.is_valid_esdt_identifier(),
+            "Invalid collection provided!"
+        );
+        require!(
+            self.lottery_info(&lottery_name)
+                .get()
+                .token_identifier
+                .is_esdt(),
+            "Token-gated lotteries must have an ESDT ticket token!"
+        );
+
+        self.required_collection(&lottery_name).set(collection);
     }
 
-    fn calculate_percentage_of(&self, value: &BigUint, percentage: &BigUint) -> BigUint {
-        value * percentage / PERCENTAGE_TOTAL
+    /// Stops the ticket sales, sponsorships and draw of a single lottery, until it is unpaused.
+    /// Claims, refunds and cancellation stay available.
+    #[only_owner]
+    #[endpoint(pauseLottery)]
+    fn pause_lottery(&self, lottery_name: ManagedBuffer) {
+        require!(
+            self.status(&lottery_name) != Status::Inactive,
+            "Lottery is inactive!"
+        );
+
+        self.lottery_paused(&lottery_name).set(true);
+        self.lottery_paused_event(&lottery_name);
     }
 
-    #[endpoint]
-    fn set_roles(&self, token_identifier: TokenIdentifier) {
-        self.send()
-            .esdt_system_sc_tx()
-            .set_special_roles(
-                &self.blockchain().get_sc_address(),
-                &token_identifier,
-                [EsdtLocalRole::Burn].into_iter(),
-            )
-            .async_call_and_exit()
+    #[only_owner]
+    #[endpoint(unpauseLottery)]
+    fn unpause_lottery(&self, lottery_name: ManagedBuffer) {
+        require!(
+            self.lottery_paused(&lottery_name).get(),
+            "Lottery is not paused!"
+        );
+
+        self.lottery_paused(&lottery_name).clear();
+        self.lottery_unpaused_event(&lottery_name);
     }
 
-    // storage
+    /// Stops a running lottery. Can only be called by the creator of the lottery or the contract owner.
+    /// Every ticket bought so far becomes refundable, and the NFT prizes can be claimed back by the creator right away.
+    #[endpoint(cancelLottery)]
+    fn cancel_lottery(&self, lottery_name: ManagedBuffer) {
+        let status = self.status(&lottery_name);
+        require!(
+            status == Status::Running || status == Status::Pending,
+            "Only running lotteries can be cancelled!"
+        );
 
-    #[view(getLotteryInfo)]
-    #[storage_mapper("lotteryInfo")]
-    fn lottery_info(
-        &self,
-        lottery_name: &ManagedBuffer,
-    ) -> SingleValueMapper<LotteryInfo<Self::Api>>;
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_lottery_creator(&lottery_name, &caller)
+                || caller == self.blockchain().get_owner_address(),
+            "Only the lottery creator or the owner can cancel the lottery!"
+        );
 
-    #[view(getLotteryWhitelist)]
-    #[storage_mapper("lotteryWhitelist")]
-    fn lottery_whitelist(&self, lottery_name: &ManagedBuffer)
-        -> UnorderedSetMapper<ManagedAddress>;
+        self.lottery_cancelled_event(&lottery_name, &caller);
 
-    #[storage_mapper("ticketHolder")]
-    fn ticket_holders(&self, lottery_name: &ManagedBuffer) -> VecMapper<ManagedAddress>;
+        if self.tickets_sold(&lottery_name) == 0 {
+            self.clear_storage(&lottery_name);
+            return;
+        }
 
-    #[storage_mapper("numberOfEntriesForUser")]
-    fn number_of_entries_for_user(
-        &self,
-        lottery_name: &ManagedBuffer,
-        user: &ManagedAddress,
-    ) -> SingleValueMapper<usize>;
+        self.lottery_cancelled(&lottery_name).set(true);
+        self.return_nft_prizes(&lottery_name);
+    }
+
+    /// Stops a recurring lottery from opening a new round after the current one is drawn.
+    /// Can only be called by the creator of the lottery or the contract owner.
+    #[endpoint(stopRecurrence)]
+    fn stop_recurrence(&self, lottery_name: ManagedBuffer) {
+        require!(
+            !self.lottery_recurrence(&lottery_name).is_empty(),
+            "Lottery is not recurring!"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_lottery_creator(&lottery_name, &caller)
+                || caller == self.blockchain().get_owner_address(),
+            "Only the lottery creator or the owner can stop the recurrence!"
+        );
+
+        self.lottery_recurrence(&lottery_name).clear();
+    }
+
+    /// Moves the deadline of a lottery that has not ended yet further away,
+    /// still within the maximum duration from now. Only affects the current round of a recurring lottery.
+    #[endpoint(extendDeadline)]
+    fn extend_deadline(&self, lottery_name: ManagedBuffer, new_deadline: u64) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+
+        let info_mapper = self.lottery_info(&lottery_name);
+        let mut info = info_mapper.get();
+        require!(
+            new_deadline > info.deadline,
+            "The new deadline must be later than the current one!"
+        );
+        // the lotteries started before their start was recorded are still bounded from now
+        let start_time_mapper = self.lottery_start_time(&lottery_name);
+        let start_time = if start_time_mapper.is_empty() {
+            self.blockchain().get_block_timestamp()
+        } else {
+            start_time_mapper.get()
+        };
+        require!(
+            new_deadline <= start_time + self.get_max_duration(),
+            "Deadline can't be later than the maximum duration from the start!"
+        );
+
+        info.deadline = new_deadline;
+        info_mapper.set(&info);
+        self.deadline_extended_event(&lottery_name, new_deadline);
+    }
+
+    /// Puts more tickets on sale in a lottery that has not ended yet.
+    /// Only affects the current round of a recurring lottery.
+    #[endpoint(increaseTicketSupply)]
+    fn increase_ticket_supply(&self, lottery_name: ManagedBuffer, additional_tickets: usize) {
+        self.require_running_or_pending(&lottery_name);
+        self.require_lottery_creator(&lottery_name);
+        require!(additional_tickets > 0, "Must add more than 0 tickets!");
+
+        let info_mapper = self.lottery_info(&lottery_name);
+        let mut info = info_mapper.get();
+        let total_tickets =
+            self.tickets_sold(&lottery_name) + info.tickets_left + additional_tickets;
+        require!(
+            total_tickets <= self.get_max_tickets(),
+            "Total tickets can't exceed the maximum per lottery!"
+        );
+
+        info.tickets_left += additional_tickets;
+        info_mapper.set(&info);
+        self.ticket_supply_increased_event(&lottery_name, additional_tickets, info.tickets_left);
+    }
+
+    /// Sends back the ticket payments and sponsorships of the caller, if the lottery has been cancelled
+    /// or has not reached its minimum tickets.
+    /// The lottery is cleared once every ticket and sponsorship has been refunded.
+    #[endpoint(claimRefund)]
+    fn claim_refund(&self, lottery_name: ManagedBuffer) {
+        self.require_refundable(&lottery_name);
+        self.return_nft_prizes(&lottery_name);
+
+        let caller = self.blockchain().get_caller();
+        let entries_mapper = self.number_of_entries_for_user(&lottery_name, &caller);
+        let entries = entries_mapper.get();
+        let sponsorship = self
+            .sponsorships(&lottery_name)
+            .remove(&caller)
+            .unwrap_or_default();
+        require!(entries > 0 || sponsorship > 0, "No tickets to refund!");
+
+        let info_mapper = self.lottery_info(&lottery_name);
+        let mut info = info_mapper.get();
+        let ticket_refund = if self.ticket_tiers(&lottery_name).is_empty() {
+            &info.ticket_price * &BigUint::from(entries)
+        } else {
+            self.amount_paid_by_user(&lottery_name, &caller).take()
+        };
+
+        entries_mapper.clear();
+        info.prize_pool -= &ticket_refund;
+        let sponsored_prize_pool =
+            self.sponsored_prize_pool(&lottery_name)
+                .update(|sponsored_prize_pool| {
+                    *sponsored_prize_pool -= &sponsorship;
+                    sponsored_prize_pool.clone()
+                });
+        if info.prize_pool == 0 && sponsored_prize_pool == 0 {
+            self.clear_storage(&lottery_name);
+        } else {
+            info_mapper.set(&info);
+        }
+
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&info.token_identifier, 0, &(ticket_refund + sponsorship))
+            .transfer();
+    }
+
+    /// Makes the NFT prizes of a failed lottery claimable by its creator, without waiting for every ticket to be refunded.
+    /// Anyone can call it, as the prizes only ever go back to the creator.
+    #[endpoint(reclaimNftPrizes)]
+    fn reclaim_nft_prizes(&self, lottery_name: ManagedBuffer) {
+        self.require_refundable(&lottery_name);
+
+        self.return_nft_prizes(&lottery_name);
+    }
+
+    #[view]
+    fn status(&self, lottery_name: &ManagedBuffer) -> Status {
+        if self.lottery_info(lottery_name).is_empty() {
+            return Status::Inactive;
+        }
+
+        if self.lottery_cancelled(lottery_name).get() {
+            return Status::Cancelled;
+        }
+
+        let info = self.lottery_info(lottery_name).get();
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time < self.lottery_start_time(lottery_name).get() {
+            return Status::Pending;
+        }
+
+        if current_time > info.deadline || info.tickets_left == 0 {
+            // a lottery without any tickets sold has nothing to refund, so it simply ends
+            let tickets_sold = self.tickets_sold(lottery_name);
+            if tickets_sold > 0 && tickets_sold < self.min_tickets(lottery_name).get() {
+                return Status::Failed;
+            }
+
+            return Status::Ended;
+        }
+
+        Status::Running
+    }
+
+    #[view(getLotteryResults)]
+    fn get_lottery_results(
+        &self,
+        lottery_name: ManagedBuffer,
+        round: usize,
+    ) -> LotteryResults<Self::Api> {
+        let results_mapper = self.lottery_results(&lottery_name, round);
+        require!(!results_mapper.is_empty(), "No results for this round!");
+
+        results_mapper.get()
+    }
+
+    #[view(getLotteryHistory)]
+    fn get_lottery_history(
+        &self,
+        lottery_name: ManagedBuffer,
+    ) -> MultiValueEncoded<LotteryResults<Self::Api>> {
+        let mut history = MultiValueEncoded::new();
+        for round in 1..=self.lottery_rounds(&lottery_name).get() {
+            history.push(self.lottery_results(&lottery_name, round).get());
+        }
+
+        history
+    }
+
+    #[view(getClaimablePrizes)]
+    fn get_claimable_prizes(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, EgldOrEsdtTokenPayment>> {
+        let mut result = MultiValueEncoded::new();
+        for lottery_name in self.claimable_lotteries(&address).iter() {
+            for prize in self.claimable_prizes(&address, &lottery_name).iter() {
+                result.push((lottery_name.clone(), prize).into());
+            }
+        }
+
+        result
+    }
+
+    #[view(getLotteryCreator)]
+    fn get_lottery_creator(
+        &self,
+        lottery_name: ManagedBuffer,
+    ) -> MultiValue2<ManagedAddress, BigUint> {
+        require!(
+            !self.lottery_creator(&lottery_name).is_empty(),
+            "Lottery does not exist!"
+        );
+
+        let creator = self.lottery_creator(&lottery_name).get();
+        let creator_fee_percentage = self.creator_fee_percentage_for_lottery(&lottery_name).get();
+
+        (creator, creator_fee_percentage).into()
+    }
+
+    /// The prize distribution of any lottery, converted to basis points.
+    #[view(getPrizeDistributionBasisPoints)]
+    fn get_prize_distribution_basis_points_view(
+        &self,
+        lottery_name: ManagedBuffer,
+    ) -> ManagedVec<u16> {
+        require!(
+            !self.lottery_info(&lottery_name).is_empty(),
+            "Lottery does not exist!"
+        );
+
+        let info = self.lottery_info(&lottery_name).get();
+        self.get_prize_distribution_basis_points(&lottery_name, &info)
+    }
+
+    /// Returns at most `size` whitelisted addresses, skipping the first `from` ones.
+    #[view(getWhitelistedAddresses)]
+    fn get_whitelisted_addresses(
+        &self,
+        lottery_name: ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.get_addresses_page(&self.lottery_whitelist(&lottery_name), from, size)
+    }
+
+    /// Returns at most `size` blacklisted addresses, skipping the first `from` ones.
+    #[view(getBlacklistedAddresses)]
+    fn get_blacklisted_addresses(
+        &self,
+        lottery_name: ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.get_addresses_page(&self.lottery_blacklist(&lottery_name), from, size)
+    }
+
+    fn get_addresses_page(
+        &self,
+        addresses: &UnorderedSetMapper<ManagedAddress>,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let mut page = MultiValueEncoded::new();
+        let first_index = match from.checked_add(1) {
+            Some(first_index) => first_index,
+            None => return page,
+        };
+        let last_index = addresses.len().min(from.saturating_add(size));
+        for index in first_index..=last_index {
+            page.push(addresses.get_by_index(index));
+        }
+
+        page
+    }
+
+    fn require_burn_role(&self, token_identifier: &EgldOrEsdtTokenIdentifier) {
+        require!(!token_identifier.is_egld(), "EGLD can't be burned!");
+
+        let esdt_token_id = token_identifier.clone().unwrap_esdt();
+        let roles = self.blockchain().get_esdt_local_roles(&esdt_token_id);
+        if !roles.has_role(&EsdtLocalRole::Burn) {
+            // the record of set_roles tells a revoked role apart from one never granted
+            if self.burn_capable_tokens().contains(&esdt_token_id) {
+                sc_panic!("The Burn role of the selected token has been revoked!");
+            }
+
+            sc_panic!("The Burn role has not been granted for the selected token! It can be requested with set_roles.");
+        }
+    }
+
+    /// Lotteries started before their creators were recorded have no creator.
+    fn is_lottery_creator(&self, lottery_name: &ManagedBuffer, address: &ManagedAddress) -> bool {
+        let creator_mapper = self.lottery_creator(lottery_name);
+        !creator_mapper.is_empty() && &creator_mapper.get() == address
+    }
+
+    fn require_lottery_creator(&self, lottery_name: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_lottery_creator(lottery_name, &caller),
+            "Only the lottery creator can change the lottery!"
+        );
+    }
+
+    /// A jackpot can only be won by the lotteries of the creator whose lotteries built it,
+    /// until its reservation expires and anyone can start a lottery with the name.
+    /// The jackpots rolled over before the upgrade have no creator, and go to the next lottery with the name.
+    fn can_win_jackpot(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        address: &ManagedAddress,
+    ) -> bool {
+        let creator_mapper = self.rollover_pool_creator(lottery_name, token_identifier);
+        self.rollover_pool(lottery_name, token_identifier).is_empty()
+            || creator_mapper.is_empty()
+            || &creator_mapper.get() == address
+            || self.blockchain().get_block_timestamp()
+                > self
+                    .rollover_pool_reserved_until(lottery_name, token_identifier)
+                    .get()
+    }
+
+    /// A lottery that has not opened yet can already be set up, like a running one.
+    fn require_running_or_pending(&self, lottery_name: &ManagedBuffer) {
+        let status = self.status(lottery_name);
+        require!(
+            status == Status::Running || status == Status::Pending,
+            "Lottery is not running!"
+        );
+    }
+
+    fn require_refundable(&self, lottery_name: &ManagedBuffer) {
+        let status = self.status(lottery_name);
+        require!(
+            status == Status::Failed || status == Status::Cancelled,
+            "Lottery is not refundable!"
+        );
+    }
+
+    /// Ticket sales, sponsorships and draws are stopped while either the contract or the lottery is paused.
+    fn require_lottery_not_paused(&self, lottery_name: &ManagedBuffer) {
+        self.require_not_paused();
+        require!(
+            !self.lottery_paused(lottery_name).get(),
+            "Lottery is paused!"
+        );
+    }
+
+    fn require_editable_participant_lists(&self, lottery_name: &ManagedBuffer) {
+        self.require_running_or_pending(lottery_name);
+        self.require_lottery_creator(lottery_name);
+    }
+
+    /// Returns the ticket payment of a token-gated purchase, after sending the collection token back.
+    fn split_token_gated_payment(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> (EgldOrEsdtTokenIdentifier, BigUint) {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(
+            payments.len() == 2,
+            "A token of the required collection must be sent along with the ticket payment!"
+        );
+
+        let ticket_payment = payments.get(0);
+        let collection_payment = payments.get(1);
+        require!(
+            collection_payment.token_identifier == self.required_collection(lottery_name).get()
+                && collection_payment.token_nonce > 0,
+            "A token of the required collection must be sent along with the ticket payment!"
+        );
+        require!(ticket_payment.token_nonce == 0, "Wrong ticket fee!");
+
+        self.tx()
+            .to(&self.blockchain().get_caller())
+            .payment(collection_payment)
+            .transfer();
+
+        (
+            EgldOrEsdtTokenIdentifier::esdt(ticket_payment.token_identifier),
+            ticket_payment.amount,
+        )
+    }
+
+    fn update_after_buy_ticket(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        payment: &BigUint,
+        partial_fill: bool,
+        tier: usize,
+        opt_merkle_proof: Option<ManagedVec<ManagedByteArray<Self::Api, HASH_LENGTH>>>,
+    ) {
+        self.migrate_legacy_tickets(lottery_name);
+        let info_mapper = self.lottery_info(lottery_name);
+        let mut info = info_mapper.get();
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            !self.lottery_blacklist(lottery_name).contains(&caller),
+            "You are blacklisted from this lottery!"
+        );
+        require!(
+            self.is_allowed_to_participate(lottery_name, &caller, opt_merkle_proof),
+            "You are not allowed to participate in this lottery!"
+        );
+
+        let mut tiers_mapper = self.ticket_tiers(lottery_name);
+        let tiered = !tiers_mapper.is_empty();
+        let mut opt_ticket_tier = None;
+        if tier != BASE_TIER {
+            require!(tier <= tiers_mapper.len(), "Invalid ticket tier!");
+            opt_ticket_tier = Some(tiers_mapper.get(tier));
+        }
+
+        let (ticket_price, tier_weight, tier_tickets_left) = match &opt_ticket_tier {
+            Some(ticket_tier) => (
+                ticket_tier.ticket_price.clone(),
+                ticket_tier.weight,
+                ticket_tier.tickets_left,
+            ),
+            None => (info.ticket_price.clone(), 1, info.tickets_left),
+        };
+
+        require!(
+            token_identifier == &info.token_identifier
+                && payment > &0
+                && (payment % &ticket_price) == 0,
+            "Wrong ticket fee!"
+        );
+
+        let entries_mapper = self.number_of_entries_for_user(lottery_name, &caller);
+        let mut entries = entries_mapper.get();
+        require!(
+            entries < info.max_entries_per_user,
+            "Ticket limit exceeded for this lottery!"
+        );
+
+        let requested_tickets = self.tickets_for_payment(payment, &ticket_price);
+        let entries_left = info.max_entries_per_user - entries;
+        let tickets_left = info.tickets_left.min(tier_tickets_left);
+        let tickets_bought = if partial_fill {
+            requested_tickets.min(entries_left).min(tickets_left)
+        } else {
+            require!(
+                requested_tickets <= entries_left,
+                "Ticket limit exceeded for this lottery!"
+            );
+            require!(
+                requested_tickets <= tickets_left,
+                "Not enough tickets left!"
+            );
+            requested_tickets
+        };
+        require!(tickets_bought > 0, "Not enough tickets left!");
+
+        let first_ticket_id = self.tickets_sold(lottery_name) + 1;
+        let last_ticket_id = first_ticket_id + tickets_bought - 1;
+        let total_cost = &ticket_price * &BigUint::from(tickets_bought);
+        if tiered {
+            self.add_weighted_ticket_range(
+                lottery_name,
+                &caller,
+                first_ticket_id,
+                last_ticket_id,
+                tier_weight,
+            );
+            self.amount_paid_by_user(lottery_name, &caller)
+                .update(|amount_paid| *amount_paid += &total_cost);
+        } else {
+            self.add_ticket_range(lottery_name, &caller, first_ticket_id, last_ticket_id);
+        }
+
+        if let Some(mut ticket_tier) = opt_ticket_tier {
+            ticket_tier.tickets_left -= tickets_bought;
+            tiers_mapper.set(tier, &ticket_tier);
+        }
+
+        entries += tickets_bought;
+        info.tickets_left -= tickets_bought;
+        info.prize_pool += &total_cost;
+
+        entries_mapper.set(entries);
+        info_mapper.set(&info);
+        self.ticket_bought_event(
+            lottery_name,
+            &caller,
+            tickets_bought,
+            first_ticket_id,
+            last_ticket_id,
+            tier,
+        );
+
+        if &total_cost < payment {
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(token_identifier, 0, &(payment - &total_cost))
+                .transfer();
+        }
+    }
+
+    /// Purchases are stored as ranges of ticket ids, so the storage only grows with the number of purchases.
+    /// A purchase right after another one of the same buyer extends the existing range.
+    fn add_ticket_range(
+        &self,
+        lottery_name: &ManagedBuffer,
+        buyer: &ManagedAddress,
+        first_ticket_id: usize,
+        last_ticket_id: usize,
+    ) {
+        let mut ranges_mapper = self.ticket_ranges(lottery_name);
+        let ranges_len = ranges_mapper.len();
+        if ranges_len > 0 {
+            let mut last_range = ranges_mapper.get(ranges_len);
+            if &last_range.buyer == buyer {
+                last_range.last_ticket_id = last_ticket_id;
+                ranges_mapper.set(ranges_len, &last_range);
+                return;
+            }
+        }
+
+        ranges_mapper.push(&TicketRange {
+            buyer: buyer.clone(),
+            first_ticket_id,
+            last_ticket_id,
+        });
+    }
+
+    /// Tickets bought before the upgrade to ticket ranges are stored one holder per ticket id.
+    /// They are converted to ranges before the next purchase or draw of their lottery.
+    fn migrate_legacy_tickets(&self, lottery_name: &ManagedBuffer) {
+        let mut legacy_holders_mapper = self.legacy_ticket_holders(lottery_name);
+        if legacy_holders_mapper.is_empty() {
+            return;
+        }
+
+        for ticket_id in 1..=legacy_holders_mapper.len() {
+            let holder = legacy_holders_mapper.get(ticket_id);
+            self.add_ticket_range(lottery_name, &holder, ticket_id, ticket_id);
+        }
+
+        legacy_holders_mapper.clear();
+    }
+
+    fn is_allowed_to_participate(
+        &self,
+        lottery_name: &ManagedBuffer,
+        caller: &ManagedAddress,
+        opt_merkle_proof: Option<ManagedVec<ManagedByteArray<Self::Api, HASH_LENGTH>>>,
+    ) -> bool {
+        let whitelist = self.lottery_whitelist(lottery_name);
+        let merkle_root_mapper = self.whitelist_merkle_root(lottery_name);
+        let whitelist_enabled = !whitelist.is_empty() || self.whitelist_enabled(lottery_name).get();
+        if !whitelist_enabled && merkle_root_mapper.is_empty() {
+            return true;
+        }
+
+        if whitelist.contains(caller) {
+            return true;
+        }
+
+        match opt_merkle_proof {
+            Some(proof) if !merkle_root_mapper.is_empty() => {
+                self.verify_merkle_proof(caller, &proof, &merkle_root_mapper.get())
+            }
+            _ => false,
+        }
+    }
+
+    fn verify_merkle_proof(
+        &self,
+        address: &ManagedAddress,
+        proof: &ManagedVec<ManagedByteArray<Self::Api, HASH_LENGTH>>,
+        merkle_root: &ManagedByteArray<Self::Api, HASH_LENGTH>,
+    ) -> bool {
+        let mut hash = self.crypto().keccak256(address.as_managed_buffer());
+        for sibling in proof.iter() {
+            let mut pair = ManagedBuffer::new();
+            if hash.to_byte_array() <= sibling.to_byte_array() {
+                pair.append(hash.as_managed_buffer());
+                pair.append(sibling.as_managed_buffer());
+            } else {
+                pair.append(sibling.as_managed_buffer());
+                pair.append(hash.as_managed_buffer());
+            }
+
+            hash = self.crypto().keccak256(&pair);
+        }
+
+        &hash == merkle_root
+    }
+
+    /// In lotteries with ticket tiers, every ticket takes as many draw slots as the weight of its tier.
+    /// The last draw slot of each range is kept at the same index as the range itself.
+    fn add_weighted_ticket_range(
+        &self,
+        lottery_name: &ManagedBuffer,
+        buyer: &ManagedAddress,
+        first_ticket_id: usize,
+        last_ticket_id: usize,
+        weight: usize,
+    ) {
+        let mut last_slots_mapper = self.ticket_range_last_slot(lottery_name);
+        let slots_taken = if last_slots_mapper.is_empty() {
+            0
+        } else {
+            last_slots_mapper.get(last_slots_mapper.len())
+        };
+        // the slots are counted in u64, as a usize is only 32 bits on wasm
+        let last_slot = ((last_ticket_id - first_ticket_id + 1) as u64)
+            .checked_mul(weight as u64)
+            .and_then(|range_slots| range_slots.checked_add(slots_taken))
+            .unwrap_or_else(|| sc_panic!("Too many draw slots!"));
+
+        self.ticket_ranges(lottery_name).push(&TicketRange {
+            buyer: buyer.clone(),
+            first_ticket_id,
+            last_ticket_id,
+        });
+        last_slots_mapper.push(&last_slot);
+    }
+
+    fn tickets_sold(&self, lottery_name: &ManagedBuffer) -> usize {
+        let ranges_mapper = self.ticket_ranges(lottery_name);
+        if ranges_mapper.is_empty() {
+            return self.legacy_ticket_holders(lottery_name).len();
+        }
+
+        ranges_mapper.get(ranges_mapper.len()).last_ticket_id
+    }
+
+    /// Binary search over the ticket ranges, which are sorted by their ticket ids.
+    fn get_ticket_holder(&self, lottery_name: &ManagedBuffer, ticket_id: usize) -> ManagedAddress {
+        let ranges_mapper = self.ticket_ranges(lottery_name);
+        let mut low = 1;
+        let mut high = ranges_mapper.len();
+
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let range = ranges_mapper.get(mid);
+            if ticket_id < range.first_ticket_id {
+                high = mid - 1;
+            } else if ticket_id > range.last_ticket_id {
+                low = mid + 1;
+            } else {
+                return range.buyer;
+            }
+        }
+
+        sc_panic!("Ticket not found!");
+    }
+
+    fn tickets_for_payment(&self, payment: &BigUint, ticket_price: &BigUint) -> usize {
+        let ticket_count = (payment / ticket_price).to_u64().unwrap_or(u64::MAX);
+
+        usize::try_from(ticket_count).unwrap_or(usize::MAX)
+    }
+
+    fn distribute_prizes(&self, lottery_name: &ManagedBuffer) -> LotteryResults<Self::Api> {
+        let mut info = self.lottery_info(lottery_name).get();
+        let total_tickets = self.tickets_sold(lottery_name);
+        let mut results = LotteryResults {
+            round: 0,
+            token_identifier: info.token_identifier.clone(),
+            winners: ManagedVec::new(),
+            burn_amount: BigUint::zero(),
+            draw_timestamp: self.blockchain().get_block_timestamp(),
+        };
+
+        if total_tickets == 0 {
+            self.return_nft_prizes(lottery_name);
+            if self.rollover_enabled(lottery_name).get() {
+                let sponsored_prize_pool = self.sponsored_prize_pool(lottery_name).take();
+                self.sponsorships(lottery_name).clear();
+                self.roll_over(lottery_name, &info.token_identifier, &sponsored_prize_pool);
+            } else {
+                self.return_sponsorships(lottery_name);
+            }
+
+            return results;
+        }
+
+        let total_pool = info.prize_pool.clone();
+        let sponsored_prize_pool = self.sponsored_prize_pool(lottery_name).take();
+        self.sponsorships(lottery_name).clear();
+        let burn_base = if self.sponsorships_excluded_from_burn(lottery_name).get() {
+            info.prize_pool.clone()
+        } else {
+            &info.prize_pool + &sponsored_prize_pool
+        };
+        info.prize_pool += sponsored_prize_pool;
+
+        let burn_basis_points = self.get_burn_basis_points(lottery_name);
+        if burn_basis_points > 0 {
+            let burn_amount = self.calculate_percentage_of(&burn_base, &burn_basis_points);
+
+            let fee_destination_mapper = self.fee_destination(lottery_name);
+            if !fee_destination_mapper.is_empty() {
+                self.redirect_burn(
+                    lottery_name,
+                    &fee_destination_mapper.get(),
+                    &info.token_identifier,
+                    &burn_amount,
+                );
+            } else {
+                // Prevent crashing if the role was unset while the lottery was running
+                // The tokens go to the fallback destination instead of being locked forever
+                let esdt_token_id = info.token_identifier.clone().unwrap_esdt();
+                let roles = self.blockchain().get_esdt_local_roles(&esdt_token_id);
+                if roles.has_role(&EsdtLocalRole::Burn) {
+                    self.send().esdt_local_burn(&esdt_token_id, 0, &burn_amount);
+                    self.tokens_burned_event(lottery_name, &esdt_token_id, &burn_amount);
+                } else {
+                    self.redirect_burn(
+                        lottery_name,
+                        &self.get_fallback_fee_destination(),
+                        &info.token_identifier,
+                        &burn_amount,
+                    );
+                }
+            }
+
+            info.prize_pool -= &burn_amount;
+            results.burn_amount = burn_amount;
+        }
+
+        let creator_fee_percentage = self.creator_fee_percentage_for_lottery(lottery_name).get();
+        if creator_fee_percentage > 0 {
+            let creator_fee = self.calculate_percentage_of(
+                &total_pool,
+                &(creator_fee_percentage * BASIS_POINTS_PER_PERCENT),
+            );
+            let creator = self.lottery_creator(lottery_name).get();
+            self.add_claimable_prize(
+                &creator,
+                lottery_name,
+                EgldOrEsdtTokenPayment::new(info.token_identifier.clone(), 0, creator_fee.clone()),
+            );
+
+            info.prize_pool -= creator_fee;
+        }
+
+        let keeper_reward_mapper = self.keeper_reward(lottery_name);
+        if !keeper_reward_mapper.is_empty() {
+            let keeper_reward = match keeper_reward_mapper.get() {
+                KeeperReward::Percentage(percentage) => self.calculate_percentage_of(
+                    &info.prize_pool,
+                    &(percentage * BASIS_POINTS_PER_PERCENT),
+                ),
+                KeeperReward::FixedAmount(amount) => amount.min(info.prize_pool.clone()),
+            };
+            let keeper = self.blockchain().get_caller();
+            self.add_claimable_prize(
+                &keeper,
+                lottery_name,
+                EgldOrEsdtTokenPayment::new(
+                    info.token_identifier.clone(),
+                    0,
+                    keeper_reward.clone(),
+                ),
+            );
+            self.keeper_reward_paid_event(
+                lottery_name,
+                &keeper,
+                &info.token_identifier,
+                &keeper_reward,
+            );
+
+            info.prize_pool -= keeper_reward;
+        }
+
+        // the jackpot of the previous draws is taken out before the part of this pool is rolled over
+        let jackpot = self
+            .rollover_pool(lottery_name, &info.token_identifier)
+            .take();
+        self.rollover_pool_creator(lottery_name, &info.token_identifier)
+            .clear();
+        self.rollover_pool_reserved_until(lottery_name, &info.token_identifier)
+            .clear();
+        let rollover_percentage = self.rollover_percentage_for_lottery(lottery_name).get();
+        if rollover_percentage > 0 {
+            let rollover_amount = self.calculate_percentage_of(
+                &info.prize_pool,
+                &(rollover_percentage * BASIS_POINTS_PER_PERCENT),
+            );
+            self.roll_over(lottery_name, &info.token_identifier, &rollover_amount);
+            info.prize_pool -= rollover_amount;
+        }
+        info.prize_pool += jackpot;
+
+        // if there are less tickets (or less distinct holders, in unique winner mode) than the distributed prize pool,
+        // the 1st place gets the leftover, maybe could split between the remaining
+        // but this is a rare case anyway and it's not worth the overhead
+        let prize_distribution = self.get_prize_distribution_basis_points(lottery_name, &info);
+        let max_winning_tickets = if total_tickets < prize_distribution.len() {
+            total_tickets
+        } else {
+            prize_distribution.len()
+        };
+        let total_prize = info.prize_pool.clone();
+        let winning_tickets = if self.unique_winners(lottery_name).get() {
+            self.get_unique_winner_tickets(lottery_name, total_tickets, max_winning_tickets)
+        } else if self.ticket_tiers(lottery_name).is_empty() {
+            self.get_distinct_random(1, total_tickets, max_winning_tickets)
+        } else {
+            self.get_distinct_weighted_random(lottery_name, max_winning_tickets)
+        };
+        let total_winning_tickets = winning_tickets.len();
+        let mut other_winners = ManagedVec::<Self::Api, WinnerInfo<Self::Api>>::new();
+
+        // distribute to the first place last. Laws of probability say that order doesn't matter.
+        // this is done to mitigate the effects of BigUint division leading to "spare" prize money being left out at times
+        // 1st place will get the spare money instead.
+        for i in (1..total_winning_tickets).rev() {
+            let winning_ticket_id = winning_tickets.get(i);
+            let winner_address = self.get_ticket_holder(lottery_name, winning_ticket_id);
+            let prize = self
+                .calculate_percentage_of(&total_prize, &BigUint::from(prize_distribution.get(i)));
+
+            self.add_claimable_prize(
+                &winner_address,
+                lottery_name,
+                EgldOrEsdtTokenPayment::new(info.token_identifier.clone(), 0, prize.clone()),
+            );
+            info.prize_pool -= &prize;
+            if prize > 0 {
+                self.prize_paid_event(
+                    lottery_name,
+                    i + 1,
+                    &winner_address,
+                    &info.token_identifier,
+                    &prize,
+                );
+            }
+            other_winners.push(WinnerInfo {
+                rank: i + 1,
+                ticket_id: winning_ticket_id,
+                winner: winner_address,
+                prize,
+            });
+        }
+
+        // send leftover to first place
+        let first_place_winner = self.get_ticket_holder(lottery_name, winning_tickets.get(0));
+        self.add_claimable_prize(
+            &first_place_winner,
+            lottery_name,
+            EgldOrEsdtTokenPayment::new(info.token_identifier.clone(), 0, info.prize_pool.clone()),
+        );
+        if info.prize_pool > 0 {
+            self.prize_paid_event(
+                lottery_name,
+                1,
+                &first_place_winner,
+                &info.token_identifier,
+                &info.prize_pool,
+            );
+        }
+
+        results.winners.push(WinnerInfo {
+            rank: 1,
+            ticket_id: winning_tickets.get(0),
+            winner: first_place_winner,
+            prize: info.prize_pool,
+        });
+        for i in (0..other_winners.len()).rev() {
+            results.winners.push(other_winners.get(i));
+        }
+
+        for winner_info in results.winners.iter() {
+            self.award_nft_prizes(lottery_name, winner_info.rank, &winner_info.winner);
+        }
+        self.return_nft_prizes(lottery_name);
+
+        results
+    }
+
+    /// The burn share is added to the claimable prizes of the destination, like the creator fee.
+    fn redirect_burn(
+        &self,
+        lottery_name: &ManagedBuffer,
+        destination: &ManagedAddress,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.add_claimable_prize(
+            destination,
+            lottery_name,
+            EgldOrEsdtTokenPayment::new(token_identifier.clone(), 0, amount.clone()),
+        );
+        self.burn_redirected_event(lottery_name, destination, token_identifier, amount);
+    }
+
+    fn award_nft_prizes(&self, lottery_name: &ManagedBuffer, rank: usize, winner: &ManagedAddress) {
+        let mut nft_prizes_mapper = self.nft_prizes(lottery_name, rank);
+        for nft_prize in nft_prizes_mapper.iter() {
+            self.nft_prize_paid_event(
+                lottery_name,
+                rank,
+                winner,
+                &nft_prize.token_identifier,
+                nft_prize.token_nonce,
+                &nft_prize.amount,
+            );
+            self.add_claimable_prize(winner, lottery_name, nft_prize.into());
+        }
+
+        nft_prizes_mapper.clear();
+    }
+
+    /// Makes the NFT prizes still held for a lottery claimable by its creator.
+    fn return_nft_prizes(&self, lottery_name: &ManagedBuffer) {
+        for rank in 1..=self.number_of_prizes(lottery_name) {
+            let mut nft_prizes_mapper = self.nft_prizes(lottery_name, rank);
+            for nft_prize in nft_prizes_mapper.iter() {
+                let creator = self.lottery_creator(lottery_name).get();
+                self.add_claimable_prize(&creator, lottery_name, nft_prize.into());
+            }
+
+            nft_prizes_mapper.clear();
+        }
+    }
+
+    fn roll_over(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if amount == &0 {
+            return;
+        }
+
+        self.rollover_pool(lottery_name, token_identifier)
+            .update(|rollover_pool| *rollover_pool += amount);
+        let creator_mapper = self.lottery_creator(lottery_name);
+        if !creator_mapper.is_empty() {
+            self.rollover_pool_creator(lottery_name, token_identifier)
+                .set(creator_mapper.get());
+            let reserved_until = self.blockchain().get_block_timestamp() + THIRTY_DAYS_IN_SECONDS;
+            self.rollover_pool_reserved_until(lottery_name, token_identifier)
+                .set(reserved_until);
+        }
+        self.jackpot_rolled_over_event(lottery_name, token_identifier, amount);
+    }
+
+    /// Makes the sponsorships still held for a lottery claimable by their sponsors.
+    fn return_sponsorships(&self, lottery_name: &ManagedBuffer) {
+        let token_identifier = self.lottery_info(lottery_name).get().token_identifier;
+        let mut sponsorships = self.sponsorships(lottery_name);
+        for (sponsor, amount) in sponsorships.iter() {
+            self.add_claimable_prize(
+                &sponsor,
+                lottery_name,
+                EgldOrEsdtTokenPayment::new(token_identifier.clone(), 0, amount),
+            );
+        }
+
+        sponsorships.clear();
+        self.sponsored_prize_pool(lottery_name).clear();
+    }
+
+    fn number_of_prizes(&self, lottery_name: &ManagedBuffer) -> usize {
+        let info = self.lottery_info(lottery_name).get();
+        self.get_prize_distribution_basis_points(lottery_name, &info)
+            .len()
+    }
+
+    /// Keeps the outcome of every draw, as the lottery's own storage is cleared right after.
+    fn archive_results(
+        &self,
+        lottery_name: &ManagedBuffer,
+        mut results: LotteryResults<Self::Api>,
+    ) {
+        let round = self.lottery_rounds(lottery_name).update(|rounds| {
+            *rounds += 1;
+            *rounds
+        });
+
+        results.round = round;
+        self.lottery_results(lottery_name, round).set(results);
+    }
+
+    /// Prizes are only recorded when the winners are determined, each winner has to claim them.
+    /// This keeps a failing transfer from blocking the whole draw.
+    fn add_claimable_prize(
+        &self,
+        winner: &ManagedAddress,
+        lottery_name: &ManagedBuffer,
+        prize: EgldOrEsdtTokenPayment,
+    ) {
+        if prize.amount == 0 {
+            return;
+        }
+
+        let mut prizes_mapper = self.claimable_prizes(winner, lottery_name);
+        for index in 1..=prizes_mapper.len() {
+            let mut existing_prize = prizes_mapper.get(index);
+            if existing_prize.token_identifier == prize.token_identifier
+                && existing_prize.token_nonce == prize.token_nonce
+            {
+                existing_prize.amount += prize.amount;
+                prizes_mapper.set(index, &existing_prize);
+                return;
+            }
+        }
+
+        prizes_mapper.push(&prize);
+        self.claimable_lotteries(winner)
+            .insert(lottery_name.clone());
+    }
+
+    /// Opens a new round of a recurring lottery, with the same settings as the one just drawn.
+    /// The whitelist, burn percentage and creator fee are kept as they are.
+    fn start_next_round(&self, lottery_name: &ManagedBuffer) {
+        self.clear_tickets(lottery_name);
+
+        let recurrence = self.lottery_recurrence(lottery_name).get();
+        let info_mapper = self.lottery_info(lottery_name);
+        let mut info = info_mapper.get();
+        info.tickets_left = recurrence.total_tickets;
+        let timestamp = self.blockchain().get_block_timestamp();
+        info.deadline = timestamp + recurrence.duration;
+        info.prize_pool = BigUint::zero();
+        info_mapper.set(&info);
+        self.round(lottery_name)
+            .set(self.lottery_rounds(lottery_name).get() + 1);
+        self.lottery_start_time(lottery_name).set(timestamp);
+
+        let mut tiers_mapper = self.ticket_tiers(lottery_name);
+        for tier in 1..=tiers_mapper.len() {
+            let mut ticket_tier = tiers_mapper.get(tier);
+            ticket_tier.tickets_left = ticket_tier.supply;
+            tiers_mapper.set(tier, &ticket_tier);
+        }
+
+        self.emit_lottery_started_event(
+            lottery_name,
+            &self.lottery_creator(lottery_name).get(),
+            &info,
+        );
+    }
+
+    fn emit_lottery_started_event(
+        &self,
+        lottery_name: &ManagedBuffer,
+        creator: &ManagedAddress,
+        info: &LotteryInfo<Self::Api>,
+    ) {
+        let recurrence_mapper = self.lottery_recurrence(lottery_name);
+        let recurrence = if recurrence_mapper.is_empty() {
+            None
+        } else {
+            Some(recurrence_mapper.get())
+        };
+
+        self.lottery_started_event(
+            lottery_name,
+            creator,
+            &self.get_burn_basis_points(lottery_name),
+            &self.creator_fee_percentage_for_lottery(lottery_name).get(),
+            &self.get_prize_distribution_basis_points(lottery_name, info),
+            self.lottery_whitelist(lottery_name).len(),
+            &recurrence,
+            info,
+        );
+    }
+
+    /// The ticket storage moves to new keys instead of being deleted entry by entry,
+    /// so clearing it costs the same whatever the number of buyers.
+    fn clear_tickets(&self, lottery_name: &ManagedBuffer) {
+        // at most 800 tickets could be bought before the upgrade
+        self.legacy_ticket_holders(lottery_name).clear();
+        self.tickets_generation(lottery_name)
+            .update(|generation| *generation += 1);
+    }
+
+    fn clear_storage(&self, lottery_name: &ManagedBuffer) {
+        self.return_nft_prizes(lottery_name);
+        self.return_sponsorships(lottery_name);
+        self.clear_tickets(lottery_name);
+        self.sponsorships_excluded_from_burn(lottery_name).clear();
+        self.rollover_enabled(lottery_name).clear();
+        self.rollover_percentage_for_lottery(lottery_name).clear();
+        self.keeper_reward(lottery_name).clear();
+        self.unique_winners(lottery_name).clear();
+        self.lottery_info(lottery_name).clear();
+        self.lottery_whitelist(lottery_name).clear();
+        self.whitelist_enabled(lottery_name).clear();
+        self.whitelist_merkle_root(lottery_name).clear();
+        self.lottery_blacklist(lottery_name).clear();
+        self.required_collection(lottery_name).clear();
+        self.burn_percentage_for_lottery(lottery_name).clear();
+        self.burn_basis_points_for_lottery(lottery_name).clear();
+        self.fee_destination(lottery_name).clear();
+        self.prize_distribution_basis_points(lottery_name).clear();
+        self.ticket_tiers(lottery_name).clear();
+        self.lottery_creator(lottery_name).clear();
+        self.creator_fee_percentage_for_lottery(lottery_name)
+            .clear();
+        self.lottery_cancelled(lottery_name).clear();
+        self.lottery_paused(lottery_name).clear();
+        self.lottery_start_time(lottery_name).clear();
+        self.min_tickets(lottery_name).clear();
+        self.round(lottery_name).clear();
+        self.lottery_recurrence(lottery_name).clear();
+        self.lottery_cleared_event(lottery_name);
+    }
+
+    fn sum_array(&self, array: &ManagedVec<u16>) -> u32 {
+        let mut sum = 0;
+
+        for item in array {
+            sum += item as u32;
+        }
+
+        sum
+    }
+
+    /// does not check if max - min >= amount, that is the caller's job
+    ///
+    /// Shuffles the numbers from min to max without materialising them:
+    /// only the positions touched by a swap are kept in memory, every other position holds `min + position`.
+    fn get_distinct_random(&self, min: usize, max: usize, amount: usize) -> ManagedVec<usize> {
+        let total_numbers = max - min + 1;
+        let mut swapped_positions = ManagedVec::<Self::Api, usize>::new();
+        let mut swapped_numbers = ManagedVec::<Self::Api, usize>::new();
+        let mut rand = RandomnessSource::new();
+
+        for i in 0..amount {
+            let rand_index = rand.next_usize_in_range(0, total_numbers);
+            let number_at_i = self.number_at(min, &swapped_positions, &swapped_numbers, i);
+            let number_at_rand_index =
+                self.number_at(min, &swapped_positions, &swapped_numbers, rand_index);
+
+            self.set_number_at(
+                &mut swapped_positions,
+                &mut swapped_numbers,
+                i,
+                number_at_rand_index,
+            );
+            self.set_number_at(
+                &mut swapped_positions,
+                &mut swapped_numbers,
+                rand_index,
+                number_at_i,
+            );
+        }
+
+        let mut rand_numbers = ManagedVec::new();
+        for i in 0..amount {
+            rand_numbers.push(self.number_at(min, &swapped_positions, &swapped_numbers, i));
+        }
+
+        rand_numbers
+    }
+
+    /// Draws distinct tickets, each with odds proportional to the weight of its tier.
+    /// The draw slots of the tickets already drawn are skipped, so that no draw has to be repeated.
+    fn get_distinct_weighted_random(
+        &self,
+        lottery_name: &ManagedBuffer,
+        amount: usize,
+    ) -> ManagedVec<usize> {
+        let last_slots_mapper = self.ticket_range_last_slot(lottery_name);
+        let total_slots = last_slots_mapper.get(last_slots_mapper.len());
+        // first slot and weight of the drawn tickets, sorted by their slots
+        let mut drawn_first_slots = ManagedVec::<Self::Api, u64>::new();
+        let mut drawn_weights = ManagedVec::<Self::Api, u64>::new();
+        let mut drawn_slots = 0;
+        let mut winning_tickets = ManagedVec::new();
+        let mut rand = RandomnessSource::new();
+
+        for _ in 0..amount {
+            let mut slot = rand.next_u64_in_range(1, total_slots - drawn_slots + 1);
+            let mut insert_index = drawn_first_slots.len();
+            for i in 0..drawn_first_slots.len() {
+                let drawn_first_slot = drawn_first_slots.get(i);
+                if drawn_first_slot > slot {
+                    insert_index = i;
+                    break;
+                }
+
+                slot += drawn_weights.get(i);
+            }
+
+            let (ticket_id, first_slot, weight) = self.ticket_at_slot(lottery_name, slot);
+            drawn_first_slots = self.insert_at(&drawn_first_slots, insert_index, first_slot);
+            drawn_weights = self.insert_at(&drawn_weights, insert_index, weight);
+            drawn_slots += weight;
+            winning_tickets.push(ticket_id);
+        }
+
+        winning_tickets
+    }
+
+    /// Draws tickets of distinct holders, each with odds proportional to the weight of its tier.
+    /// A ticket of a holder that already won is drawn again, at most `MAX_UNIQUE_WINNER_DRAWS` times per rank,
+    /// after which the rank is drawn among the tickets of the holders left.
+    /// Stops early once every holder has won, so there can be less winning tickets than `amount`.
+    fn get_unique_winner_tickets(
+        &self,
+        lottery_name: &ManagedBuffer,
+        total_tickets: usize,
+        amount: usize,
+    ) -> ManagedVec<usize> {
+        let last_slots_mapper = self.ticket_range_last_slot(lottery_name);
+        let total_slots = if last_slots_mapper.is_empty() {
+            0
+        } else {
+            last_slots_mapper.get(last_slots_mapper.len())
+        };
+        let mut winners = ManagedMap::new();
+        let mut winning_tickets = ManagedVec::new();
+        let mut rand = RandomnessSource::new();
+
+        for _ in 0..amount {
+            let mut winning_ticket = None;
+            for _ in 0..MAX_UNIQUE_WINNER_DRAWS {
+                let ticket_id = if total_slots == 0 {
+                    rand.next_usize_in_range(1, total_tickets + 1)
+                } else {
+                    self.ticket_at_slot(lottery_name, rand.next_u64_in_range(1, total_slots + 1))
+                        .0
+                };
+                let holder = self.get_ticket_holder(lottery_name, ticket_id);
+                if !winners.contains(holder.as_managed_buffer()) {
+                    winners.put(holder.as_managed_buffer(), &ManagedBuffer::from(WON));
+                    winning_ticket = Some(ticket_id);
+                    break;
+                }
+            }
+
+            if winning_ticket.is_none() {
+                winning_ticket =
+                    self.draw_ticket_of_new_holder(lottery_name, &mut winners, &mut rand);
+            }
+
+            match winning_ticket {
+                Some(ticket_id) => winning_tickets.push(ticket_id),
+                None => break,
+            }
+        }
+
+        winning_tickets
+    }
+
+    /// Draws among the tickets of the holders that haven't won yet, going over the ticket ranges twice.
+    /// Returns `None` once every holder has won.
+    fn draw_ticket_of_new_holder(
+        &self,
+        lottery_name: &ManagedBuffer,
+        winners: &mut ManagedMap<Self::Api>,
+        rand: &mut RandomnessSource,
+    ) -> Option<usize> {
+        let ranges_mapper = self.ticket_ranges(lottery_name);
+        let mut eligible_slots = 0;
+        for index in 1..=ranges_mapper.len() {
+            let range = ranges_mapper.get(index);
+            if !winners.contains(range.buyer.as_managed_buffer()) {
+                let (range_slots, _) = self.range_slots(lottery_name, index, &range);
+                eligible_slots += range_slots;
+            }
+        }
+        if eligible_slots == 0 {
+            return None;
+        }
+
+        let mut slot = rand.next_u64_in_range(1, eligible_slots + 1);
+        for index in 1..=ranges_mapper.len() {
+            let range = ranges_mapper.get(index);
+            if winners.contains(range.buyer.as_managed_buffer()) {
+                continue;
+            }
+
+            let (range_slots, weight) = self.range_slots(lottery_name, index, &range);
+            if slot <= range_slots {
+                winners.put(range.buyer.as_managed_buffer(), &ManagedBuffer::from(WON));
+                return Some(range.first_ticket_id + ((slot - 1) / weight) as usize);
+            }
+
+            slot -= range_slots;
+        }
+
+        None
+    }
+
+    /// Returns the draw slots taken by a ticket range, along with the weight of its tickets.
+    fn range_slots(
+        &self,
+        lottery_name: &ManagedBuffer,
+        index: usize,
+        range: &TicketRange<Self::Api>,
+    ) -> (u64, u64) {
+        let range_tickets = (range.last_ticket_id - range.first_ticket_id + 1) as u64;
+        let last_slots_mapper = self.ticket_range_last_slot(lottery_name);
+        if last_slots_mapper.is_empty() {
+            return (range_tickets, 1);
+        }
+
+        let previous_last_slot = if index > 1 {
+            last_slots_mapper.get(index - 1)
+        } else {
+            0
+        };
+        let range_slots = last_slots_mapper.get(index) - previous_last_slot;
+
+        (range_slots, range_slots / range_tickets)
+    }
+
+    /// Returns the ticket taking the given draw slot, along with its first slot and weight.
+    fn ticket_at_slot(&self, lottery_name: &ManagedBuffer, slot: u64) -> (usize, u64, u64) {
+        let last_slots_mapper = self.ticket_range_last_slot(lottery_name);
+        let mut low = 1;
+        let mut high = last_slots_mapper.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if last_slots_mapper.get(mid) < slot {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        let range = self.ticket_ranges(lottery_name).get(low);
+        let previous_last_slot = if low > 1 {
+            last_slots_mapper.get(low - 1)
+        } else {
+            0
+        };
+        let range_tickets = (range.last_ticket_id - range.first_ticket_id + 1) as u64;
+        let weight = (last_slots_mapper.get(low) - previous_last_slot) / range_tickets;
+        let ticket_offset = (slot - previous_last_slot - 1) / weight;
+
+        (
+            range.first_ticket_id + ticket_offset as usize,
+            previous_last_slot + ticket_offset * weight + 1,
+            weight,
+        )
+    }
+
+    fn insert_at(&self, items: &ManagedVec<u64>, index: usize, item: u64) -> ManagedVec<u64> {
+        let mut result = ManagedVec::new();
+        for (i, existing_item) in items.iter().enumerate() {
+            if i == index {
+                result.push(item);
+            }
+            result.push(existing_item);
+        }
+        if index == items.len() {
+            result.push(item);
+        }
+
+        result
+    }
+
+    fn number_at(
+        &self,
+        min: usize,
+        swapped_positions: &ManagedVec<usize>,
+        swapped_numbers: &ManagedVec<usize>,
+        position: usize,
+    ) -> usize {
+        match swapped_positions
+            .iter()
+            .position(|swapped| swapped == position)
+        {
+            Some(index) => swapped_numbers.get(index),
+            None => min + position,
+        }
+    }
+
+    fn set_number_at(
+        &self,
+        swapped_positions: &mut ManagedVec<usize>,
+        swapped_numbers: &mut ManagedVec<usize>,
+        position: usize,
+        number: usize,
+    ) {
+        match swapped_positions
+            .iter()
+            .position(|swapped| swapped == position)
+        {
+            Some(index) => {
+                let _ = swapped_numbers.set(index, &number);
+            }
+            None => {
+                swapped_positions.push(position);
+                swapped_numbers.push(number);
+            }
+        }
+    }
+
+    fn widen_prize_distribution(
+        &self,
+        opt_prize_distribution: ManagedOption<ManagedVec<u8>>,
+    ) -> ManagedOption<ManagedVec<u16>> {
+        opt_prize_distribution.map(|prize_distribution| {
+            let mut widened = ManagedVec::new();
+            for percentage in &prize_distribution {
+                widened.push(percentage as u16);
+            }
+
+            widened
+        })
+    }
+
+    fn get_burn_basis_points(&self, lottery_name: &ManagedBuffer) -> BigUint {
+        let burn_basis_points_mapper = self.burn_basis_points_for_lottery(lottery_name);
+        if !burn_basis_points_mapper.is_empty() {
+            return burn_basis_points_mapper.get();
+        }
+
+        self.burn_percentage_for_lottery(lottery_name).get() * BASIS_POINTS_PER_PERCENT
+    }
+
+    fn get_prize_distribution_basis_points(
+        &self,
+        lottery_name: &ManagedBuffer,
+        info: &LotteryInfo<Self::Api>,
+    ) -> ManagedVec<u16> {
+        let basis_points_mapper = self.prize_distribution_basis_points(lottery_name);
+        if !basis_points_mapper.is_empty() {
+            return basis_points_mapper.get();
+        }
+
+        let mut prize_distribution = ManagedVec::new();
+        for percentage in &info.prize_distribution {
+            prize_distribution.push(percentage as u16 * BASIS_POINTS_PER_PERCENT as u16);
+        }
+
+        prize_distribution
+    }
+
+    /// `basis_points` out of 10_000
+    fn calculate_percentage_of(&self, value: &BigUint, basis_points: &BigUint) -> BigUint {
+        value * basis_points / BASIS_POINTS_TOTAL
+    }
+
+    /// Requests the Burn role of a token for the contract. The outcome is recorded by the callback.
+    #[only_owner]
+    #[endpoint]
+    fn set_roles(&self, token_identifier: TokenIdentifier) {
+        self.send()
+            .esdt_system_sc_tx()
+            .set_special_roles(
+                &self.blockchain().get_sc_address(),
+                &token_identifier,
+                [EsdtLocalRole::Burn].into_iter(),
+            )
+            .callback(self.callbacks().set_roles_callback(token_identifier))
+            .async_call_and_exit()
+    }
+
+    #[callback]
+    fn set_roles_callback(
+        &self,
+        token_identifier: TokenIdentifier,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.granted_roles(&token_identifier)
+                    .insert(EsdtLocalRole::Burn);
+                self.burn_capable_tokens().insert(token_identifier.clone());
+                self.roles_granted_event(&token_identifier, EsdtLocalRole::Burn);
+            }
+            ManagedAsyncCallResult::Err(error) => {
+                self.roles_grant_failed_event(
+                    &token_identifier,
+                    EsdtLocalRole::Burn,
+                    error.err_code,
+                    &error.err_msg,
+                );
+            }
+        }
+    }
+
+    // storage
+
+    #[view(getGrantedRoles)]
+    #[storage_mapper("grantedRoles")]
+    fn granted_roles(
+        &self,
+        token_identifier: &TokenIdentifier,
+    ) -> UnorderedSetMapper<EsdtLocalRole>;
+
+    #[view(getBurnCapableTokens)]
+    #[storage_mapper("burnCapableTokens")]
+    fn burn_capable_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    #[view(getLotteryInfo)]
+    #[storage_mapper("lotteryInfo")]
+    fn lottery_info(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<LotteryInfo<Self::Api>>;
+
+    #[view(getLotteryWhitelist)]
+    #[storage_mapper("lotteryWhitelist")]
+    fn lottery_whitelist(&self, lottery_name: &ManagedBuffer)
+        -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Set once addresses are removed from the whitelist, so that emptying it doesn't open the lottery to everyone.
+    #[view(isWhitelistEnabled)]
+    #[storage_mapper("whitelistEnabled")]
+    fn whitelist_enabled(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("lotteryBlacklist")]
+    fn lottery_blacklist(&self, lottery_name: &ManagedBuffer)
+        -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getRequiredCollection)]
+    #[storage_mapper("requiredCollection")]
+    fn required_collection(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getWhitelistMerkleRoot)]
+    #[storage_mapper("whitelistMerkleRoot")]
+    fn whitelist_merkle_root(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, HASH_LENGTH>>;
+
+    /// Counts how many times the tickets of a lottery name have been cleared.
+    /// Never cleared itself, so the keys of the ticket storage are never reused.
+    #[storage_mapper("ticketsGeneration")]
+    fn tickets_generation(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    /// The keys of the first generation are the ones used before the generations were counted.
+    fn tickets_storage_key(
+        &self,
+        base_key: &[u8],
+        lottery_name: &ManagedBuffer,
+    ) -> StorageKey<Self::Api> {
+        let mut key = StorageKey::new(base_key);
+        key.append_item(lottery_name);
+        let generation = self.tickets_generation(lottery_name).get();
+        if generation > 0 {
+            key.append_item(&generation);
+        }
+
+        key
+    }
+
+    fn ticket_ranges(&self, lottery_name: &ManagedBuffer) -> VecMapper<TicketRange<Self::Api>> {
+        VecMapper::new(self.tickets_storage_key(b"ticketRanges", lottery_name))
+    }
+
+    #[storage_mapper("ticketHolder")]
+    fn legacy_ticket_holders(&self, lottery_name: &ManagedBuffer) -> VecMapper<ManagedAddress>;
+
+    fn ticket_range_last_slot(&self, lottery_name: &ManagedBuffer) -> VecMapper<u64> {
+        VecMapper::new(self.tickets_storage_key(b"ticketRangeLastSlot", lottery_name))
+    }
+
+    #[view(getFeeDestination)]
+    #[storage_mapper("feeDestination")]
+    fn fee_destination(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getTicketTiers)]
+    #[storage_mapper("ticketTiers")]
+    fn ticket_tiers(&self, lottery_name: &ManagedBuffer) -> VecMapper<TicketTier<Self::Api>>;
+
+    fn amount_paid_by_user(
+        &self,
+        lottery_name: &ManagedBuffer,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint> {
+        let mut key = self.tickets_storage_key(b"amountPaidByUser", lottery_name);
+        key.append_item(user);
+        SingleValueMapper::new(key)
+    }
+
+    fn number_of_entries_for_user(
+        &self,
+        lottery_name: &ManagedBuffer,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<usize> {
+        let mut key = self.tickets_storage_key(b"numberOfEntriesForUser", lottery_name);
+        key.append_item(user);
+        SingleValueMapper::new(key)
+    }
+
+    #[storage_mapper("lotteryCreator")]
+    fn lottery_creator(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("creatorFeePercentageForLottery")]
+    fn creator_fee_percentage_for_lottery(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(isLotteryPaused)]
+    #[storage_mapper("lotteryPaused")]
+    fn lottery_paused(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    /// When the current round opens, or opened.
+    #[view(getLotteryStartTime)]
+    #[storage_mapper("lotteryStartTime")]
+    fn lottery_start_time(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(getMinTickets)]
+    #[storage_mapper("minTickets")]
+    fn min_tickets(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("lotteryCancelled")]
+    fn lottery_cancelled(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[view(getLotteryRecurrence)]
+    #[storage_mapper("lotteryRecurrence")]
+    fn lottery_recurrence(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<RecurrenceConfig>;
+
+    /// The round that is currently open, counted from 1.
+    #[view(getRound)]
+    #[storage_mapper("round")]
+    fn round(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("lotteryRounds")]
+    fn lottery_rounds(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("lotteryResults")]
+    fn lottery_results(
+        &self,
+        lottery_name: &ManagedBuffer,
+        round: usize,
+    ) -> SingleValueMapper<LotteryResults<Self::Api>>;
+
+    #[view(getSponsoredPrizePool)]
+    #[storage_mapper("sponsoredPrizePool")]
+    fn sponsored_prize_pool(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("sponsorships")]
+    fn sponsorships(&self, lottery_name: &ManagedBuffer) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[view(areSponsorshipsExcludedFromBurn)]
+    #[storage_mapper("sponsorshipsExcludedFromBurn")]
+    fn sponsorships_excluded_from_burn(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("rolloverEnabled")]
+    fn rollover_enabled(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[view(areWinnersUnique)]
+    #[storage_mapper("uniqueWinners")]
+    fn unique_winners(&self, lottery_name: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    #[view(getKeeperReward)]
+    #[storage_mapper("keeperReward")]
+    fn keeper_reward(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<KeeperReward<Self::Api>>;
+
+    #[view(getRolloverPercentageForLottery)]
+    #[storage_mapper("rolloverPercentageForLottery")]
+    fn rollover_percentage_for_lottery(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Kept when the lottery is cleared, so that the jackpot goes to the next lottery with the same name.
+    #[view(getRolloverPool)]
+    #[storage_mapper("rolloverPool")]
+    fn rollover_pool(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getRolloverPoolCreator)]
+    #[storage_mapper("rolloverPoolCreator")]
+    fn rollover_pool_creator(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    /// The jackpot is left to any lottery with the name once this timestamp has passed.
+    #[view(getRolloverPoolReservedUntil)]
+    #[storage_mapper("rolloverPoolReservedUntil")]
+    fn rollover_pool_reserved_until(
+        &self,
+        lottery_name: &ManagedBuffer,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<u64>;
+
+    #[view(getNftPrizes)]
+    #[storage_mapper("nftPrizes")]
+    fn nft_prizes(
+        &self,
+        lottery_name: &ManagedBuffer,
+        rank: usize,
+    ) -> VecMapper<EsdtTokenPayment<Self::Api>>;
+
+    #[storage_mapper("claimablePrizes")]
+    fn claimable_prizes(
+        &self,
+        user: &ManagedAddress,
+        lottery_name: &ManagedBuffer,
+    ) -> VecMapper<EgldOrEsdtTokenPayment<Self::Api>>;
+
+    #[storage_mapper("claimableLotteries")]
+    fn claimable_lotteries(&self, user: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("burnBasisPointsForLottery")]
+    fn burn_basis_points_for_lottery(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("prizeDistributionBasisPoints")]
+    fn prize_distribution_basis_points(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedVec<u16>>;
 
     #[storage_mapper("burnPercentageForLottery")]
     fn burn_percentage_for_lottery(
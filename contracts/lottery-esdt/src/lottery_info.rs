@@ -5,6 +5,7 @@ use multiversx_sc::{
 
 use multiversx_sc::derive_imports::*;
 
+/// Stored as is by every version of the contract, so new lottery options get their own storage instead of a field.
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
 pub struct LotteryInfo<M: ManagedTypeApi> {
@@ -16,3 +17,11 @@ pub struct LotteryInfo<M: ManagedTypeApi> {
     pub prize_distribution: ManagedVec<M, u8>,
     pub prize_pool: BigUint<M>,
 }
+
+/// What a recurring lottery needs, on top of its `LotteryInfo`, to open the next round.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct RecurrenceConfig {
+    pub total_tickets: usize,
+    pub duration: u64,
+}
@@ -0,0 +1,25 @@
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    types::{BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedVec},
+};
+
+use multiversx_sc::derive_imports::*;
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, ManagedVecItem)]
+pub struct WinnerInfo<M: ManagedTypeApi> {
+    pub rank: usize,
+    pub ticket_id: usize,
+    pub winner: ManagedAddress<M>,
+    pub prize: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct LotteryResults<M: ManagedTypeApi> {
+    pub round: usize,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub winners: ManagedVec<M, WinnerInfo<M>>,
+    pub burn_amount: BigUint<M>,
+    pub draw_timestamp: u64,
+}
@@ -6,4 +6,7 @@ pub enum Status {
     Inactive,
     Running,
     Ended,
+    Failed,
+    Cancelled,
+    Pending,
 }
@@ -0,0 +1,12 @@
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedAddress};
+
+use multiversx_sc::derive_imports::*;
+
+/// Consecutive tickets owned by the same buyer, from `first_ticket_id` to `last_ticket_id` inclusive.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TicketRange<M: ManagedTypeApi> {
+    pub buyer: ManagedAddress<M>,
+    pub first_ticket_id: usize,
+    pub last_ticket_id: usize,
+}
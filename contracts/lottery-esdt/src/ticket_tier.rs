@@ -0,0 +1,14 @@
+use multiversx_sc::{api::ManagedTypeApi, types::BigUint};
+
+use multiversx_sc::derive_imports::*;
+
+/// A class of tickets with its own price and supply.
+/// Each ticket of the tier counts `weight` times when the winners are drawn.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TicketTier<M: ManagedTypeApi> {
+    pub ticket_price: BigUint<M>,
+    pub supply: usize,
+    pub tickets_left: usize,
+    pub weight: usize,
+}
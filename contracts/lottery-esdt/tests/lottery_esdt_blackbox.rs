@@ -1,4 +1,9 @@
+use lottery_esdt::config::THIRTY_DAYS_IN_SECONDS;
 use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::keccak256;
+use multiversx_sc_scenario::scenario_model::{
+    CheckLog, CheckLogList, CheckLogs, CheckValue, TxResponse,
+};
 
 mod proxy;
 
@@ -10,6 +15,9 @@ const SC_ADDRESS: TestSCAddress = TestSCAddress::new("lottery-esdt");
 const CODE_PATH: MxscPath = MxscPath::new("../output/lottery-esdt.mxsc.json");
 const TOKEN_IDENTIFIER: TestTokenIdentifier = TestTokenIdentifier::new("BSK-476470");
 const TOKEN_BURNABLE: TestTokenIdentifier = TestTokenIdentifier::new("TEST-123456");
+const NFT_COLLECTION: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+
+type MerkleProof = ManagedVec<StaticApi, ManagedByteArray<StaticApi, 32>>;
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -18,6 +26,216 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+fn merkle_leaf(address: TestAddress) -> [u8; 32] {
+    keccak256(address.to_address().as_bytes())
+}
+
+fn merkle_parent(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if left <= right { (left, right) } else { (right, left) };
+    keccak256(&[first, second].concat())
+}
+
+/// Checks the logs of a transaction, in order.
+/// The Rust VM doesn't return logs in the transaction response, so they are checked through the expect field.
+struct ExpectLogs(Vec<CheckLog>);
+
+impl<Env, Original> RHListItem<Env, Original> for ExpectLogs
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+}
+
+impl<Env, Original> RHListItemExec<TxResponse, Env, Original> for ExpectLogs
+where
+    Env: TxEnv<RHExpect = TxExpect>,
+{
+    fn item_tx_expect(&self, mut prev: TxExpect) -> TxExpect {
+        prev.logs = CheckLogs::List(CheckLogList {
+            list: self.0.clone(),
+            more_allowed_at_end: false,
+        });
+        prev
+    }
+
+    fn item_process_result(self, _: &TxResponse) -> Self::Returns {}
+}
+
+/// An event of the lottery contract, with the values written in the scenario format.
+fn event_log(event_name: &str, topics: &[&str], data: &[&str]) -> CheckLog {
+    let mut expected_topics = vec![CheckValue::Equal(BytesValue::from(
+        format!("str:{event_name}").as_str(),
+    ))];
+    expected_topics.extend(topics.iter().map(|topic| CheckValue::Equal(BytesValue::from(*topic))));
+
+    CheckLog {
+        address: CheckValue::Equal(BytesValue::from(SC_ADDRESS.eval_to_expr().as_str())),
+        endpoint: CheckValue::Star,
+        topics: CheckValue::Equal(expected_topics),
+        data: CheckValue::Equal(
+            data.iter()
+                .map(|value| CheckValue::Equal(BytesValue::from(*value)))
+                .collect(),
+        ),
+    }
+}
+
+enum StartEndpoint {
+    Start,
+    StartWithBasisPoints,
+    CreateLotteryPool,
+}
+
+/// The arguments of a lottery start. By default the owner starts "test", with 2 tickets of 1 BSK-476470,
+/// a deadline at 20, at most 2 tickets per user, a 75/25 prize split and a whitelist of the first two addresses.
+struct LotteryStart {
+    creator: TestAddress<'static>,
+    endpoint: StartEndpoint,
+    lottery_name: &'static str,
+    token_identifier: EgldOrEsdtTokenIdentifier<StaticApi>,
+    ticket_price: u64,
+    total_tickets: Option<u32>,
+    deadline: Option<u64>,
+    max_entries_per_user: Option<u32>,
+    prize_distribution: Option<Vec<u16>>,
+    whitelist: Option<Vec<TestAddress<'static>>>,
+    burn_percentage: Option<u64>,
+    min_tickets: Option<usize>,
+    creator_fee_percentage: Option<u64>,
+    recurring: Option<bool>,
+    start_time: Option<u64>,
+    fee_destination: Option<TestAddress<'static>>,
+}
+
+impl LotteryStart {
+    fn new() -> Self {
+        Self {
+            creator: OWNER_ADDRESS,
+            endpoint: StartEndpoint::Start,
+            lottery_name: "test",
+            token_identifier: EgldOrEsdtTokenIdentifier::esdt(TOKEN_IDENTIFIER),
+            ticket_price: 1,
+            total_tickets: Some(2),
+            deadline: Some(20),
+            max_entries_per_user: Some(2),
+            prize_distribution: Some(vec![75, 25]),
+            whitelist: Some(vec![FIRST_ADDRESS, SECOND_ADDRESS]),
+            burn_percentage: None,
+            min_tickets: None,
+            creator_fee_percentage: None,
+            recurring: None,
+            start_time: None,
+            fee_destination: None,
+        }
+    }
+
+    /// No ticket limit, no prize distribution and no whitelist.
+    fn unlimited() -> Self {
+        Self::new()
+            .total_tickets(None)
+            .max_entries_per_user(None)
+            .prize_distribution(None)
+            .whitelist(None)
+    }
+
+    fn creator(mut self, creator: TestAddress<'static>) -> Self {
+        self.creator = creator;
+        self
+    }
+
+    fn lottery_name(mut self, lottery_name: &'static str) -> Self {
+        self.lottery_name = lottery_name;
+        self
+    }
+
+    fn token(mut self, token_identifier: EgldOrEsdtTokenIdentifier<StaticApi>) -> Self {
+        self.token_identifier = token_identifier;
+        self
+    }
+
+    fn ticket_price(mut self, ticket_price: u64) -> Self {
+        self.ticket_price = ticket_price;
+        self
+    }
+
+    fn total_tickets(mut self, total_tickets: Option<u32>) -> Self {
+        self.total_tickets = total_tickets;
+        self
+    }
+
+    fn deadline(mut self, deadline: Option<u64>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    fn max_entries_per_user(mut self, max_entries_per_user: Option<u32>) -> Self {
+        self.max_entries_per_user = max_entries_per_user;
+        self
+    }
+
+    fn prize_distribution(mut self, prize_distribution: Option<&[u8]>) -> Self {
+        self.endpoint = StartEndpoint::Start;
+        self.prize_distribution = prize_distribution.map(|distribution| distribution.iter().map(|share| u16::from(*share)).collect());
+        self
+    }
+
+    /// Starts through `startWithBasisPoints`.
+    fn basis_points(mut self, prize_distribution: &[u16]) -> Self {
+        self.endpoint = StartEndpoint::StartWithBasisPoints;
+        self.prize_distribution = Some(prize_distribution.to_vec());
+        self
+    }
+
+    /// Starts through `createLotteryPool`.
+    fn pool(mut self) -> Self {
+        self.endpoint = StartEndpoint::CreateLotteryPool;
+        self
+    }
+
+    fn whitelist(mut self, whitelist: Option<&[TestAddress<'static>]>) -> Self {
+        self.whitelist = whitelist.map(|addresses| addresses.to_vec());
+        self
+    }
+
+    fn burn_percentage(mut self, burn_percentage: u64) -> Self {
+        self.burn_percentage = Some(burn_percentage);
+        self
+    }
+
+    fn min_tickets(mut self, min_tickets: usize) -> Self {
+        self.min_tickets = Some(min_tickets);
+        self
+    }
+
+    fn creator_fee_percentage(mut self, creator_fee_percentage: u64) -> Self {
+        self.creator_fee_percentage = Some(creator_fee_percentage);
+        self
+    }
+
+    fn recurring(mut self, recurring: bool) -> Self {
+        self.recurring = Some(recurring);
+        self
+    }
+
+    fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    fn fee_destination(mut self, fee_destination: TestAddress<'static>) -> Self {
+        self.fee_destination = Some(fee_destination);
+        self
+    }
+}
+
+fn positional<T>(value: Option<T>, neutral_value: T, needed: bool) -> OptionalValue<T> {
+    match value {
+        Some(value) => OptionalValue::Some(value),
+        None if needed => OptionalValue::Some(neutral_value),
+        None => OptionalValue::None,
+    }
+}
+
 struct LotteryESDTTestState {
     world: ScenarioWorld,
 }
@@ -26,11 +244,17 @@ impl LotteryESDTTestState{
     fn new() -> Self {
         let mut world = world();
 
-        world.account(OWNER_ADDRESS).nonce(1);
+        world
+            .account(OWNER_ADDRESS)
+            .esdt_nft_balance(NFT_COLLECTION, 2, 2, ())
+            .nonce(1);
 
         world
             .account(FIRST_ADDRESS)
+            .balance(1000)
+            .esdt_balance(TOKEN_BURNABLE, 1000)
             .esdt_balance(TOKEN_IDENTIFIER, 1000)
+            .esdt_nft_balance(NFT_COLLECTION, 1, 1, ())
             .nonce(1);
 
         world
@@ -63,374 +287,3022 @@ impl LotteryESDTTestState{
             .run();
     }
 
-    fn start_lottery(&mut self)
+    fn start_lottery(&mut self, lottery: LotteryStart)
+    {
+        self.run_start(lottery, ReturnsResult);
+    }
+
+    fn start_lottery_with_logs(&mut self, lottery: LotteryStart, logs: ExpectLogs)
+    {
+        self.run_start(lottery, logs);
+    }
+
+    fn start_lottery_error(&mut self, lottery: LotteryStart, error: ExpectError)
+    {
+        self.run_start(lottery, error);
+    }
+
+    fn run_start<RH>(&mut self, lottery: LotteryStart, returns: RH)
+    where
+        RH: for<'w> RHListItem<ScenarioEnvExec<'w>, ()>
+            + for<'w> RHListItemExec<TxResponse, ScenarioEnvExec<'w>, ()>,
+    {
+        let lottery_name = ManagedBuffer::<StaticApi>::new_from_bytes(lottery.lottery_name.as_bytes());
+        let ticket_price = BigUint::<StaticApi>::from(lottery.ticket_price);
+        let opt_whitelist = lottery.whitelist.map(|whitelist| {
+            whitelist
+                .iter()
+                .map(|address| address.to_managed_address())
+                .collect::<ManagedVec<StaticApi, ManagedAddress<StaticApi>>>()
+        });
+
+        // the optional arguments are positional, so the ones before the last set argument get a neutral value
+        let set_arguments = [
+            lottery.burn_percentage.is_some(),
+            lottery.min_tickets.is_some(),
+            lottery.creator_fee_percentage.is_some(),
+            lottery.recurring.is_some(),
+            lottery.start_time.is_some(),
+            lottery.fee_destination.is_some(),
+        ];
+        let passed_arguments = set_arguments.iter().rposition(|set| *set).map_or(0, |index| index + 1);
+        let opt_burn_percentage = positional(lottery.burn_percentage.map(BigUint::<StaticApi>::from), BigUint::zero(), passed_arguments > 0);
+        let opt_min_tickets = positional(lottery.min_tickets, 0, passed_arguments > 1);
+        let opt_creator_fee_percentage = positional(lottery.creator_fee_percentage.map(BigUint::<StaticApi>::from), BigUint::zero(), passed_arguments > 2);
+        let opt_recurring = positional(lottery.recurring, false, passed_arguments > 3);
+        let opt_start_time = positional(lottery.start_time, 0, passed_arguments > 4);
+        let opt_fee_destination = OptionalValue::from(lottery.fee_destination.map(|address| address.to_managed_address()));
+
+        let tx = self.world
+            .tx()
+            .from(lottery.creator)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy);
+
+        match lottery.endpoint {
+            StartEndpoint::Start => tx
+                .start(
+                    &lottery_name,
+                    &lottery.token_identifier,
+                    &ticket_price,
+                    lottery.total_tickets,
+                    lottery.deadline,
+                    lottery.max_entries_per_user,
+                    lottery.prize_distribution.map(|distribution| distribution.iter().map(|share| *share as u8).collect::<ManagedVec<StaticApi, u8>>()),
+                    opt_whitelist,
+                    opt_burn_percentage,
+                    opt_min_tickets,
+                    opt_creator_fee_percentage,
+                    opt_recurring,
+                    opt_start_time,
+                    opt_fee_destination,
+                )
+                .returns(returns)
+                .run(),
+            StartEndpoint::StartWithBasisPoints => tx
+                .start_with_basis_points(
+                    &lottery_name,
+                    &lottery.token_identifier,
+                    &ticket_price,
+                    lottery.total_tickets,
+                    lottery.deadline,
+                    lottery.max_entries_per_user,
+                    lottery.prize_distribution.map(|distribution| distribution.into_iter().collect::<ManagedVec<StaticApi, u16>>()),
+                    opt_whitelist,
+                    opt_burn_percentage,
+                    opt_min_tickets,
+                    opt_creator_fee_percentage,
+                    opt_recurring,
+                    opt_start_time,
+                    opt_fee_destination,
+                )
+                .returns(returns)
+                .run(),
+            StartEndpoint::CreateLotteryPool => tx
+                .create_lottery_pool(
+                    &lottery_name,
+                    &lottery.token_identifier,
+                    &ticket_price,
+                    lottery.total_tickets,
+                    lottery.deadline,
+                    lottery.max_entries_per_user,
+                    lottery.prize_distribution.map(|distribution| distribution.iter().map(|share| *share as u8).collect::<ManagedVec<StaticApi, u8>>()),
+                    opt_whitelist,
+                    opt_burn_percentage,
+                    opt_min_tickets,
+                    opt_creator_fee_percentage,
+                    opt_recurring,
+                    opt_start_time,
+                    opt_fee_destination,
+                )
+                .returns(returns)
+                .run(),
+        };
+    }
+
+    fn buy_ticket(&mut self, address: TestAddress)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
-        let token_identifier = EgldOrEsdtTokenIdentifier::esdt(&b"BSK-476470"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
         let ticket_price = BigUint::<StaticApi>::from(1u128);
-        let opt_total_tickets = Option::Some(2u32);
-        let opt_deadline = Option::Some(20u64);
-        let opt_max_entries_per_user = Option::Some(2u32);
-        let prize_distribution_data: &[u8] = &[75,25];
-        let opt_prize_distribution = Option::Some(ManagedVec::from_iter(prize_distribution_data.iter().copied()));
-        let mut whitelist = ManagedVec::new();
-        whitelist.push(FIRST_ADDRESS.to_managed_address());
-        whitelist.push(SECOND_ADDRESS.to_managed_address());
-        let opt_whitelist = Option::Some(whitelist);
-        let opt_burn_percentage: OptionalValue<BigUint<StaticApi>> = OptionalValue::None;
 
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .start(
-                &lottery_name,
-                &token_identifier,
-                &ticket_price,
-                opt_total_tickets,
-                opt_deadline,
-                opt_max_entries_per_user,
-                opt_prize_distribution,
-                opt_whitelist,
-                opt_burn_percentage,
-            )
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&ticket_price)
             .run();
     }
 
-    fn start_lottery_error(&mut self, error: ExpectError)
+    fn buy_ticket_error(&mut self, address: TestAddress, error: ExpectError)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
-        let token_identifier = EgldOrEsdtTokenIdentifier::esdt(&b"BSK-476470"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
         let ticket_price = BigUint::<StaticApi>::from(1u128);
-        let opt_total_tickets = Option::Some(2u32);
-        let opt_deadline = Option::Some(20u64);
-        let opt_max_entries_per_user = Option::Some(1u32);
-        let prize_distribution_data: &[u8] = &[75,25];
-        let opt_prize_distribution = Option::Some(ManagedVec::from_iter(prize_distribution_data.iter().copied()));
-        let mut whitelist = ManagedVec::new();
-        whitelist.push(FIRST_ADDRESS.to_managed_address());
-        whitelist.push(SECOND_ADDRESS.to_managed_address());
-        let opt_whitelist = Option::Some(whitelist);
-        let opt_burn_percentage: OptionalValue<BigUint<StaticApi>> = OptionalValue::None;
 
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .start(
-                &lottery_name,
-                &token_identifier,
-                &ticket_price,
-                opt_total_tickets,
-                opt_deadline,
-                opt_max_entries_per_user,
-                opt_prize_distribution,
-                opt_whitelist,
-                opt_burn_percentage,
-            )
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&ticket_price)
             .returns(error)
             .run();
     }
 
-    fn start_lottery_error_params(&mut self, lottery_name_wrong: bool, token_identifier_wrong:u64, ticket_price_wrong:bool, opt_total_tickets_wrong: Option<u32>, opt_deadline_wrong: Option<u64>,
-         opt_max_entries_per_user_wrong: bool, opt_prize_distribution_wrong: bool, opt_burn_percentage_wrong: bool, error: ExpectError)
+    fn buy_tickets(&mut self, address: TestAddress, amount: u64, partial_fill: bool)
     {
-        let token_id_burnable : &[u8] = &b"TEST-123456"[..];
-        let lottery_name = if lottery_name_wrong {
-            ManagedBuffer::new_from_bytes(&b""[..])
-        } else {
-            ManagedBuffer::new_from_bytes(&b"test"[..])
-        };
-
-        let ticket_price = if ticket_price_wrong {
-            BigUint::<StaticApi>::from(0u128)
-        } else {
-            BigUint::<StaticApi>::from(1u128)
-        };
-
-        let opt_max_entries_per_user = if opt_max_entries_per_user_wrong {
-            Option::Some(0u32)
-        } else {
-            Option::Some(1u32)
-        };
-
-        let prize_distribution_data: &[u8] = if opt_prize_distribution_wrong {
-            &[60,60]
-        } else {
-            &[75,25]
-        };
-
-        let token_identifier;
-
-        match token_identifier_wrong{
-            1 => token_identifier = TestTokenIdentifier::new(""),
-            2 => token_identifier = TestTokenIdentifier::new("EGLD"),
-            3 => token_identifier = TOKEN_BURNABLE,
-            _ => token_identifier = TOKEN_IDENTIFIER
-        }
-
-        let opt_prize_distribution = Option::Some(ManagedVec::from_iter(prize_distribution_data.iter().copied()));
-
-        let mut whitelist = ManagedVec::new();
-        whitelist.push(FIRST_ADDRESS.to_managed_address());
-        whitelist.push(SECOND_ADDRESS.to_managed_address());
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
+        let payment = BigUint::<StaticApi>::from(amount);
 
-        let opt_whitelist = Option::Some(whitelist);
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::Some(partial_fill), OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&payment)
+            .run();
+    }
 
-        let opt_burn_percentage = if opt_burn_percentage_wrong {
-            OptionalValue::Some(BigUint::<StaticApi>::from(101u128))
-        } else {
-            OptionalValue::Some(BigUint::<StaticApi>::from(10u128))
-        };
+    fn buy_burnable_tickets(&mut self, address: TestAddress, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let payment = BigUint::<StaticApi>::from(amount);
 
-        // self.world
-        //     .tx()
-        //     .from(THIRD_ADDRESS)
-        //     .to(SC_ADDRESS)
-        //     .typed(proxy::LotteryProxy)
-        //     .set_roles(TOKEN_BURNABLE)
-        //     .run();
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&TokenIdentifier::from(TOKEN_BURNABLE), 0, &payment)
+            .run();
+    }
 
-        self.world.set_esdt_local_roles(SC_ADDRESS, token_id_burnable, &[EsdtLocalRole::Burn]);
+    fn buy_egld_tickets(&mut self, address: TestAddress, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
 
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .start(
-                &lottery_name,
-                &token_identifier,
-                &ticket_price,
-                opt_total_tickets_wrong,
-                opt_deadline_wrong,
-                opt_max_entries_per_user,
-                opt_prize_distribution,
-                opt_whitelist,
-                opt_burn_percentage,
-            )
-            .returns(error)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .egld(amount)
             .run();
     }
 
-    fn buy_ticket(&mut self, address: TestAddress)
+    fn buy_tickets_with_logs(&mut self, address: TestAddress, amount: u64, logs: ExpectLogs)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
         let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
-        let ticket_price = BigUint::<StaticApi>::from(1u128);
+        let payment = BigUint::<StaticApi>::from(amount);
 
         self.world
             .tx()
             .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .buy_ticket(&lottery_name)
-            .single_esdt(&token_identifier, 0,&ticket_price)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&payment)
+            .returns(logs)
             .run();
     }
 
-    fn buy_ticket_error(&mut self, address: TestAddress, error: ExpectError)
+    fn buy_tickets_error(&mut self, address: TestAddress, amount: u64, error: ExpectError)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
         let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
-        let ticket_price = BigUint::<StaticApi>::from(1u128);
+        let payment = BigUint::<StaticApi>::from(amount);
 
         self.world
             .tx()
             .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .buy_ticket(&lottery_name)
-            .single_esdt(&token_identifier, 0,&ticket_price)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&payment)
             .returns(error)
             .run();
     }
 
-    fn buy_ticket_wrong_fee(&mut self, address: TestAddress, fee: BigUint<StaticApi>)
+    fn buy_ticket_wrong_fee(&mut self, address: TestAddress, token_identifier: TestTokenIdentifier, fee: BigUint<StaticApi>)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
-        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
 
         self.world
             .tx()
             .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .buy_ticket(&lottery_name)
-            .single_esdt(&token_identifier, 0,&fee)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .single_esdt(&TokenIdentifier::from(token_identifier), 0,&fee)
             .returns(ExpectError(4,"Wrong ticket fee!"))
             .run();
     }
 
-    fn determine_winner(&mut self)
+    fn add_ticket_tier(&mut self, ticket_price: u64, supply: usize, weight: usize, expected_tier: usize)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
 
-        self.world
+        let tier = self
+            .world
             .tx()
             .from(OWNER_ADDRESS)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .determine_winner(&lottery_name)
+            .add_ticket_tier(&lottery_name, BigUint::<StaticApi>::from(ticket_price), supply, weight)
             .returns(ReturnsResult)
             .run();
+
+        assert_eq!(tier, expected_tier);
     }
 
-    fn determine_winner_error(&mut self, error: ExpectError)
+    fn add_ticket_tier_error(&mut self, address: TestAddress, ticket_price: u64, supply: usize, weight: usize, error: ExpectError)
     {
         let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
 
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(address)
             .to(SC_ADDRESS)
             .typed(proxy::LotteryProxy)
-            .determine_winner(&lottery_name)
+            .add_ticket_tier(&lottery_name, BigUint::<StaticApi>::from(ticket_price), supply, weight)
             .returns(error)
             .run();
     }
 
-    fn set_time_block(&mut self, timestamp: u64){
-        self.world.current_block().block_timestamp(timestamp);
+    fn buy_tier_tickets(&mut self, address: TestAddress, tier: usize, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
+        let payment = BigUint::<StaticApi>::from(amount);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::Some(false), OptionalValue::Some(tier), OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&payment)
+            .run();
     }
 
-}
+    fn buy_tier_tickets_error(&mut self, address: TestAddress, tier: usize, amount: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
+        let payment = BigUint::<StaticApi>::from(amount);
 
-#[test]
-fn lottery_esdt_blackbox_init(){
-    let mut world = LotteryESDTTestState::new();
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::Some(false), OptionalValue::Some(tier), OptionalValue::<MerkleProof>::None)
+            .single_esdt(&token_identifier, 0,&payment)
+            .returns(error)
+            .run();
+    }
 
-    world.deploy();
+    fn set_whitelist_merkle_root_with_logs(&mut self, address: TestAddress, merkle_root: [u8; 32], logs: ExpectLogs)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
 
-}
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_whitelist_merkle_root(&lottery_name, ManagedByteArray::new_from_bytes(&merkle_root))
+            .returns(logs)
+            .run();
+    }
 
-#[test]
-fn lottery_esdt_blackbox_buy_all()
-{
-    let mut world = LotteryESDTTestState::new();
+    fn set_whitelist_merkle_root_error(&mut self, address: TestAddress, merkle_root: [u8; 32], error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
 
-    world.deploy();
-    
-    world.start_lottery();
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_whitelist_merkle_root(&lottery_name, ManagedByteArray::new_from_bytes(&merkle_root))
+            .returns(error)
+            .run();
+    }
 
-    world.buy_ticket(FIRST_ADDRESS);
+    fn buy_ticket_with_proof(&mut self, address: TestAddress, proof: &[[u8; 32]])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
+        let ticket_price = BigUint::<StaticApi>::from(1u128);
+        let merkle_proof: MerkleProof = proof.iter().map(ManagedByteArray::new_from_bytes).collect();
 
-    world.buy_ticket(SECOND_ADDRESS);
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::Some(false), OptionalValue::Some(0usize), OptionalValue::Some(merkle_proof))
+            .single_esdt(&token_identifier, 0,&ticket_price)
+            .run();
+    }
 
-    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
-}
+    fn buy_ticket_with_proof_error(&mut self, address: TestAddress, proof: &[[u8; 32]], error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = TokenIdentifier::from_esdt_bytes(&b"BSK-476470"[..]);
+        let ticket_price = BigUint::<StaticApi>::from(1u128);
+        let merkle_proof: MerkleProof = proof.iter().map(ManagedByteArray::new_from_bytes).collect();
 
-#[test]
-fn lottery_esdt_blackbox_buy_after_winner_announced() {
-    let mut world = LotteryESDTTestState::new();
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::Some(false), OptionalValue::Some(0usize), OptionalValue::Some(merkle_proof))
+            .single_esdt(&token_identifier, 0,&ticket_price)
+            .returns(error)
+            .run();
+    }
 
-    world.deploy();
+    fn add_to_whitelist(&mut self, addresses: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let addresses: MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> =
+            addresses.iter().map(|address| address.to_managed_address()).collect();
 
-    world.start_lottery();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .add_to_whitelist(&lottery_name, addresses)
+            .run();
+    }
 
-    world.buy_ticket(FIRST_ADDRESS);
+    fn remove_from_whitelist(&mut self, addresses: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let addresses: MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> =
+            addresses.iter().map(|address| address.to_managed_address()).collect();
 
-    world.buy_ticket(SECOND_ADDRESS);
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .remove_from_whitelist(&lottery_name, addresses)
+            .run();
+    }
 
-    world.determine_winner();
+    fn add_to_blacklist(&mut self, address: TestAddress, addresses: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let addresses: MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> =
+            addresses.iter().map(|address| address.to_managed_address()).collect();
 
-    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery is currently inactive."));
-    
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .add_to_blacklist(&lottery_name, addresses)
+            .run();
+    }
+
+    fn add_to_blacklist_error(&mut self, address: TestAddress, addresses: &[TestAddress], error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let addresses: MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> =
+            addresses.iter().map(|address| address.to_managed_address()).collect();
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .add_to_blacklist(&lottery_name, addresses)
+            .returns(error)
+            .run();
+    }
+
+    fn remove_from_blacklist(&mut self, addresses: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let addresses: MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> =
+            addresses.iter().map(|address| address.to_managed_address()).collect();
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .remove_from_blacklist(&lottery_name, addresses)
+            .run();
+    }
+
+    fn set_required_collection(&mut self, address: TestAddress, collection: TestTokenIdentifier)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_required_collection(&lottery_name, collection)
+            .run();
+    }
+
+    fn set_required_collection_error(&mut self, address: TestAddress, collection: TestTokenIdentifier, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_required_collection(&lottery_name, collection)
+            .returns(error)
+            .run();
+    }
+
+    fn buy_ticket_with_collection_token(&mut self, address: TestAddress, collection: TestTokenIdentifier, nonce: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .esdt(EsdtTokenPayment::new(TokenIdentifier::from(TOKEN_IDENTIFIER), 0, BigUint::from(1u64)))
+            .esdt(EsdtTokenPayment::new(TokenIdentifier::from(collection), nonce, BigUint::from(1u64)))
+            .run();
+    }
+
+    fn buy_ticket_with_collection_token_error(&mut self, address: TestAddress, collection: TestTokenIdentifier, nonce: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .buy_ticket(&lottery_name, OptionalValue::<bool>::None, OptionalValue::<usize>::None, OptionalValue::<MerkleProof>::None)
+            .esdt(EsdtTokenPayment::new(TokenIdentifier::from(TOKEN_IDENTIFIER), 0, BigUint::from(1u64)))
+            .esdt(EsdtTokenPayment::new(TokenIdentifier::from(collection), nonce, BigUint::from(1u64)))
+            .returns(error)
+            .run();
+    }
+
+    fn deposit_nft_prize(&mut self, rank: usize, token_identifier: TestTokenIdentifier, nonce: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .deposit_nft_prize(&lottery_name, rank)
+            .single_esdt(&TokenIdentifier::from(token_identifier), nonce, &BigUint::from(1u64))
+            .run();
+    }
+
+    fn deposit_nft_prize_error(&mut self, address: TestAddress, rank: usize, token_identifier: TestTokenIdentifier, nonce: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .deposit_nft_prize(&lottery_name, rank)
+            .single_esdt(&TokenIdentifier::from(token_identifier), nonce, &BigUint::from(1u64))
+            .returns(error)
+            .run();
+    }
+
+    fn sponsor_lottery(&mut self, address: TestAddress, token_identifier: TestTokenIdentifier, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .sponsor_lottery(&lottery_name)
+            .single_esdt(&TokenIdentifier::from(token_identifier), 0, &BigUint::from(amount))
+            .run();
+    }
+
+    fn sponsor_lottery_error(&mut self, address: TestAddress, token_identifier: TestTokenIdentifier, amount: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .sponsor_lottery(&lottery_name)
+            .single_esdt(&TokenIdentifier::from(token_identifier), 0, &BigUint::from(amount))
+            .returns(error)
+            .run();
+    }
+
+    fn exclude_sponsorships_from_burn(&mut self, excluded: bool)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .exclude_sponsorships_from_burn(&lottery_name, excluded)
+            .run();
+    }
+
+    fn enable_rollover(&mut self, rollover_percentage: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .enable_rollover(&lottery_name, BigUint::<StaticApi>::from(rollover_percentage))
+            .run();
+    }
+
+    fn enable_rollover_error(&mut self, rollover_percentage: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .enable_rollover(&lottery_name, BigUint::<StaticApi>::from(rollover_percentage))
+            .returns(error)
+            .run();
+    }
+
+    fn extend_deadline_with_logs(&mut self, new_deadline: u64, logs: ExpectLogs)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .extend_deadline(&lottery_name, new_deadline)
+            .returns(logs)
+            .run();
+    }
+
+    fn extend_deadline_error(&mut self, new_deadline: u64, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .extend_deadline(&lottery_name, new_deadline)
+            .returns(error)
+            .run();
+    }
+
+    fn increase_ticket_supply_with_logs(&mut self, additional_tickets: usize, logs: ExpectLogs)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .increase_ticket_supply(&lottery_name, additional_tickets)
+            .returns(logs)
+            .run();
+    }
+
+    fn increase_ticket_supply_error(&mut self, additional_tickets: usize, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .increase_ticket_supply(&lottery_name, additional_tickets)
+            .returns(error)
+            .run();
+    }
+
+    fn set_keeper_reward(&mut self, keeper_reward: proxy::KeeperReward<StaticApi>)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_keeper_reward(&lottery_name, keeper_reward)
+            .run();
+    }
+
+    fn set_keeper_reward_error(&mut self, keeper_reward: proxy::KeeperReward<StaticApi>, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_keeper_reward(&lottery_name, keeper_reward)
+            .returns(error)
+            .run();
+    }
+
+    fn determine_winner_from(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .determine_winner(&lottery_name)
+            .run();
+    }
+
+    fn set_max_tickets(&mut self, address: TestAddress, max_tickets: usize)
+    {
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_max_tickets(max_tickets)
+            .run();
+    }
+
+    fn set_max_tickets_error(&mut self, address: TestAddress, max_tickets: usize, error: ExpectError)
+    {
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_max_tickets(max_tickets)
+            .returns(error)
+            .run();
+    }
+
+    fn set_max_duration(&mut self, max_duration: u64)
+    {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_max_duration(max_duration)
+            .run();
+    }
+
+    fn add_allowed_ticket_token(&mut self, token_identifier: TestTokenIdentifier)
+    {
+        let mut tokens = MultiValueEncoded::new();
+        tokens.push(EgldOrEsdtTokenIdentifier::esdt(token_identifier));
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .add_allowed_ticket_tokens(tokens)
+            .run();
+    }
+
+    fn set_min_ticket_price(&mut self, token_identifier: TestTokenIdentifier, min_ticket_price: u64)
+    {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_min_ticket_price(EgldOrEsdtTokenIdentifier::esdt(token_identifier), BigUint::<StaticApi>::from(min_ticket_price))
+            .run();
+    }
+
+    fn pause_contract(&mut self, address: TestAddress)
+    {
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .pause_endpoint()
+            .run();
+    }
+
+    fn pause_contract_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .pause_endpoint()
+            .returns(error)
+            .run();
+    }
+
+    fn unpause_contract(&mut self)
+    {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .unpause_endpoint()
+            .run();
+    }
+
+    fn pause_lottery(&mut self)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .pause_lottery(&lottery_name)
+            .run();
+    }
+
+    fn pause_lottery_error(&mut self, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .pause_lottery(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn unpause_lottery(&mut self)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .unpause_lottery(&lottery_name)
+            .run();
+    }
+
+    fn unpause_lottery_error(&mut self, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .unpause_lottery(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn set_roles_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_roles(TOKEN_BURNABLE)
+            .returns(error)
+            .run();
+    }
+
+    /// The Rust VM can't make the system smart contract refuse a role, so the callback is called directly.
+    fn fail_set_roles_callback(&mut self, error_code: u32, error_message: &str)
+    {
+        use lottery_esdt::Lottery;
+
+        let lottery_contract = WhiteboxContract::new(SC_ADDRESS.eval_to_expr(), lottery_esdt::contract_obj);
+
+        self.world.whitebox_call_check(
+            &lottery_contract,
+            ScCallStep::new().from(OWNER_ADDRESS.eval_to_expr().as_str()),
+            |sc| {
+                sc.set_roles_callback(
+                    TokenIdentifier::from(&b"TEST-123456"[..]),
+                    ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                        err_code: error_code,
+                        err_msg: ManagedBuffer::from(error_message),
+                    }),
+                );
+            },
+            |tx_result| {
+                tx_result.assert_ok();
+
+                let log = &tx_result.result_logs[0];
+                assert_eq!(log.topics[0], b"rolesGrantFailed".to_vec());
+                assert_eq!(log.topics[1], b"TEST-123456".to_vec());
+                assert_eq!(log.topics[3], top_encode_to_vec_u8_or_panic(&error_code));
+                assert_eq!(log.data, vec![error_message.as_bytes().to_vec()]);
+            },
+        );
+    }
+
+    fn set_fallback_fee_destination(&mut self, destination: TestAddress)
+    {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .set_fallback_fee_destination(destination)
+            .run();
+    }
+
+    fn enable_unique_winners(&mut self)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .enable_unique_winners(&lottery_name)
+            .run();
+    }
+
+    fn enable_unique_winners_error(&mut self, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .enable_unique_winners(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn determine_winner(&mut self)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .determine_winner(&lottery_name)
+            .returns(ReturnsResult)
+            .run();
+    }
+
+    fn determine_winner_with_logs(&mut self, logs: ExpectLogs)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .determine_winner(&lottery_name)
+            .returns(logs)
+            .run();
+    }
+
+    fn determine_winner_error(&mut self, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .determine_winner(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn cancel_lottery(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .cancel_lottery(&lottery_name)
+            .run();
+    }
+
+    fn cancel_lottery_with_logs(&mut self, address: TestAddress, logs: ExpectLogs)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .cancel_lottery(&lottery_name)
+            .returns(logs)
+            .run();
+    }
+
+    fn cancel_lottery_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .cancel_lottery(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn stop_recurrence(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .stop_recurrence(&lottery_name)
+            .run();
+    }
+
+    fn stop_recurrence_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .stop_recurrence(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn claim_refund(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .claim_refund(&lottery_name)
+            .run();
+    }
+
+    fn claim_refund_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .claim_refund(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn reclaim_nft_prizes(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .reclaim_nft_prizes(&lottery_name)
+            .run();
+    }
+
+    fn reclaim_nft_prizes_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .reclaim_nft_prizes(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn claim_prize(&mut self, address: TestAddress)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .claim_prize(&lottery_name)
+            .run();
+    }
+
+    fn claim_prize_error(&mut self, address: TestAddress, error: ExpectError)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .tx()
+            .from(address)
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .claim_prize(&lottery_name)
+            .returns(error)
+            .run();
+    }
+
+    fn check_lottery_round(&mut self, round: usize, deadline: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let info = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .lottery_info(&lottery_name)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(info.deadline, deadline);
+        assert_eq!(info.tickets_left, 2);
+        assert_eq!(info.prize_pool, BigUint::zero());
+
+        self.world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .round(&lottery_name)
+            .returns(ExpectValue(round))
+            .run();
+    }
+
+    fn check_min_tickets(&mut self, min_tickets: usize)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .min_tickets(&lottery_name)
+            .returns(ExpectValue(min_tickets))
+            .run();
+    }
+
+    fn check_lottery_creator(&mut self, creator: TestAddress, creator_fee_percentage: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let (lottery_creator, lottery_creator_fee_percentage) = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_creator(&lottery_name)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+
+        assert_eq!(lottery_creator, creator.to_managed_address());
+        assert_eq!(lottery_creator_fee_percentage, BigUint::from(creator_fee_percentage));
+    }
+
+    fn check_claimable_prize(&mut self, address: TestAddress, amount: u64)
+    {
+        let claimable_prizes = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_claimable_prizes(address)
+            .returns(ReturnsResult)
+            .run();
+
+        let mut total_amount = BigUint::<StaticApi>::zero();
+        for prize in claimable_prizes.into_iter() {
+            let (lottery_name, payment) = prize.into_tuple();
+            assert_eq!(lottery_name, ManagedBuffer::new_from_bytes(&b"test"[..]));
+            assert_eq!(payment.token_identifier, EgldOrEsdtTokenIdentifier::esdt(TOKEN_IDENTIFIER));
+            total_amount += payment.amount;
+        }
+
+        assert_eq!(total_amount, BigUint::from(amount));
+    }
+
+    fn check_lottery_results(&mut self, round: usize, winners: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let results = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(&lottery_name, round)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(results.round, round);
+        assert_eq!(results.winners.len(), winners.len());
+
+        let mut total_prize = BigUint::<StaticApi>::zero();
+        for (index, winner_info) in results.winners.iter().enumerate() {
+            assert_eq!(winner_info.rank, index + 1);
+            assert!(winners.iter().any(|winner| winner.to_managed_address() == winner_info.winner));
+            total_prize += winner_info.prize;
+        }
+
+        assert_eq!(total_prize, BigUint::from(2u64));
+        assert_eq!(results.burn_amount, BigUint::zero());
+    }
+
+    fn check_single_winner(&mut self, ticket_ranges: &[(TestAddress, usize)])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let results = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(&lottery_name, 1usize)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(results.winners.len(), 1);
+
+        let winner_info = results.winners.get(0);
+        let (expected_winner, _) = ticket_ranges
+            .iter()
+            .find(|(_, last_ticket_id)| winner_info.ticket_id <= *last_ticket_id)
+            .unwrap();
+        assert_eq!(winner_info.winner, expected_winner.to_managed_address());
+    }
+
+    fn check_prizes_by_rank(&mut self, round: usize, prizes: &[u64])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let results = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(&lottery_name, round)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(results.winners.len(), prizes.len());
+        for (winner_info, prize) in results.winners.iter().zip(prizes.iter()) {
+            assert_eq!(winner_info.prize, BigUint::from(*prize));
+        }
+    }
+
+    fn check_whitelisted_addresses(&mut self, from: usize, size: usize, expected: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let addresses = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_whitelisted_addresses(&lottery_name, from, size)
+            .returns(ReturnsResult)
+            .run();
+
+        let addresses: Vec<ManagedAddress<StaticApi>> = addresses.into_iter().collect();
+        let expected: Vec<ManagedAddress<StaticApi>> = expected.iter().map(|address| address.to_managed_address()).collect();
+        assert_eq!(addresses, expected);
+    }
+
+    fn check_blacklisted_addresses(&mut self, from: usize, size: usize, expected: &[TestAddress])
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let addresses = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_blacklisted_addresses(&lottery_name, from, size)
+            .returns(ReturnsResult)
+            .run();
+
+        let addresses: Vec<ManagedAddress<StaticApi>> = addresses.into_iter().collect();
+        let expected: Vec<ManagedAddress<StaticApi>> = expected.iter().map(|address| address.to_managed_address()).collect();
+        assert_eq!(addresses, expected);
+    }
+
+    fn get_winner_by_rank(&mut self, round: usize, rank: usize) -> TestAddress<'static>
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let results = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(&lottery_name, round)
+            .returns(ReturnsResult)
+            .run();
+
+        let winner_info = results.winners.get(rank - 1);
+        *[FIRST_ADDRESS, SECOND_ADDRESS, THIRD_ADDRESS]
+            .iter()
+            .find(|address| address.to_managed_address() == winner_info.winner)
+            .unwrap()
+    }
+
+    fn check_nft_balance(&mut self, address: TestAddress, nonce: u64, balance: u64) {
+        self.world
+            .check_account(address)
+            .esdt_nft_balance_and_attributes(NFT_COLLECTION, nonce, balance, "");
+    }
+
+    fn check_sponsored_prize_pool(&mut self, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        self.world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .sponsored_prize_pool(&lottery_name)
+            .returns(ExpectValue(BigUint::<StaticApi>::from(amount)))
+            .run();
+    }
+
+    fn check_burn_amount(&mut self, round: usize, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let results = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_results(&lottery_name, round)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(results.burn_amount, BigUint::from(amount));
+    }
+
+    fn check_rollover_pool(&mut self, amount: u64)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+        let token_identifier = EgldOrEsdtTokenIdentifier::esdt(TOKEN_IDENTIFIER);
+
+        self.world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .rollover_pool(&lottery_name, &token_identifier)
+            .returns(ExpectValue(BigUint::<StaticApi>::from(amount)))
+            .run();
+    }
+
+    fn check_lottery_history_length(&mut self, rounds: usize)
+    {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let history = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .get_lottery_history(&lottery_name)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(history.len(), rounds);
+    }
+
+    fn check_status(&mut self, expected_status: proxy::Status) {
+        let lottery_name = ManagedBuffer::new_from_bytes(&b"test"[..]);
+
+        let status = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .status(&lottery_name)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(status == expected_status);
+    }
+
+    fn check_burn_capable_tokens(&mut self, expected: &[TestTokenIdentifier]) {
+        let tokens = self
+            .world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(proxy::LotteryProxy)
+            .burn_capable_tokens()
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(tokens.len(), expected.len());
+        for (token, expected_token) in tokens.into_iter().zip(expected.iter()) {
+            assert_eq!(token, TokenIdentifier::from(*expected_token));
+        }
+    }
+
+    fn check_esdt_balance(&mut self, address: TestAddress, balance: u64) {
+        self.world
+            .check_account(address)
+            .esdt_balance(TOKEN_IDENTIFIER, balance);
+    }
+
+    fn set_time_block(&mut self, timestamp: u64){
+        self.world.current_block().block_timestamp(timestamp);
+    }
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_init(){
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_all()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+    
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_after_winner_announced() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner();
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery is currently inactive."));
+    
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_after_deadline()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.set_time_block(30);
+    
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_after_sold_out()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+    
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_not_whitelisted()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+    
+    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_wrong_fee()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+    
+    world.buy_ticket_wrong_fee(FIRST_ADDRESS, TOKEN_BURNABLE, BigUint::<StaticApi>::from(1u128));
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_multiple_tickets()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 998);
+
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_multiple_tickets_rejected()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_tickets_error(FIRST_ADDRESS, 3, ExpectError(4,"Ticket limit exceeded for this lottery!"));
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_tickets_error(SECOND_ADDRESS, 2, ExpectError(4,"Not enough tickets left!"));
+
+    world.check_esdt_balance(SECOND_ADDRESS, 1000);
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_multiple_tickets_partial_fill()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_tickets(SECOND_ADDRESS, 2, true);
+
+    world.check_esdt_balance(SECOND_ADDRESS, 999);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+}
+
+#[test]
+fn lottery_esdt_blackbox_min_tickets_not_reached()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_error(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(5), ExpectError(4,"Minimum tickets can't be higher than the total tickets!"));
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(4));
+
+    world.check_min_tickets(4);
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.claim_refund_error(FIRST_ADDRESS, ExpectError(4,"Lottery is not refundable!"));
+
+    world.set_time_block(30);
+
+    world.check_status(proxy::Status::Failed);
+
+    world.determine_winner_error(ExpectError(4,"Lottery did not sell enough tickets! Refunds are available."));
+
+    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4,"Lottery entry period has ended! Refunds are available."));
+
+    world.claim_refund_error(THIRD_ADDRESS, ExpectError(4,"No tickets to refund!"));
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1000);
+
+    world.claim_refund_error(FIRST_ADDRESS, ExpectError(4,"No tickets to refund!"));
+
+    world.claim_refund(SECOND_ADDRESS);
+
+    world.check_esdt_balance(SECOND_ADDRESS, 1000);
+
+    world.check_status(proxy::Status::Inactive);
+
+    world.check_min_tickets(0);
+}
+
+#[test]
+fn lottery_esdt_blackbox_min_tickets_reached()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(2));
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.set_time_block(30);
+
+    world.check_status(proxy::Status::Ended);
+
+    world.claim_refund_error(FIRST_ADDRESS, ExpectError(4,"Lottery is not refundable!"));
+
+    world.determine_winner();
+}
+
+#[test]
+fn lottery_esdt_blackbox_cancel_lottery()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.cancel_lottery_error(FIRST_ADDRESS, ExpectError(4,"Only the lottery creator or the owner can cancel the lottery!"));
+
+    world.cancel_lottery_with_logs(OWNER_ADDRESS, ExpectLogs(vec![event_log(
+        "lotteryCancelled",
+        &["str:test", "address:OWNER_ADDRESS"],
+        &[""],
+    )]));
+
+    world.check_status(proxy::Status::Cancelled);
+
+    world.cancel_lottery_error(OWNER_ADDRESS, ExpectError(4,"Only running lotteries can be cancelled!"));
+
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4,"Lottery has been cancelled! Refunds are available."));
+
+    world.determine_winner_error(ExpectError(4,"Lottery has been cancelled! Refunds are available."));
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1000);
+
+    world.check_status(proxy::Status::Inactive);
+
+    world.start_lottery(LotteryStart::new());
+
+    world.check_status(proxy::Status::Running);
+
+    world.buy_ticket(SECOND_ADDRESS);
+}
+
+#[test]
+fn lottery_esdt_blackbox_cancel_lottery_without_tickets()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.cancel_lottery_with_logs(OWNER_ADDRESS, ExpectLogs(vec![
+        event_log("lotteryCancelled", &["str:test", "address:OWNER_ADDRESS"], &[""]),
+        event_log("lotteryCleared", &["str:test"], &[""]),
+    ]));
+
+    world.check_status(proxy::Status::Inactive);
+}
+
+#[test]
+fn lottery_esdt_blackbox_cancel_ended_lottery()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.cancel_lottery_error(OWNER_ADDRESS, ExpectError(4,"Only running lotteries can be cancelled!"));
+}
+
+#[test]
+fn lottery_esdt_blackbox_determine_winner_early()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+    
+    world.start_lottery(LotteryStart::new());
+    
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.determine_winner_error(ExpectError(4,"Lottery is still running!"));
+
+}
+
+#[test]
+fn lottery_esdt_blackbox_determine_winner_one_participant() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.determine_winner();
+    
+}
+
+#[test]
+fn lottery_esdt_blackbox_claim_prize() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.determine_winner();
+
+    world.check_esdt_balance(FIRST_ADDRESS, 998);
+
+    world.check_claimable_prize(FIRST_ADDRESS, 2);
+
+    world.check_claimable_prize(SECOND_ADDRESS, 0);
+
+    world.claim_prize_error(SECOND_ADDRESS, ExpectError(4,"No prizes to claim!"));
+
+    world.claim_prize(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1000);
+
+    world.check_claimable_prize(FIRST_ADDRESS, 0);
+
+    world.claim_prize_error(FIRST_ADDRESS, ExpectError(4,"No prizes to claim!"));
+}
+
+#[test]
+fn lottery_esdt_blackbox_creator_fee() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_error(LotteryStart::new().whitelist(None).creator_fee_percentage(100), ExpectError(4,"Invalid creator fee percentage!"));
+
+    world.start_lottery(LotteryStart::new().whitelist(None).creator_fee_percentage(50));
+
+    world.check_lottery_creator(OWNER_ADDRESS, 50);
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.determine_winner();
+
+    world.check_claimable_prize(OWNER_ADDRESS, 1);
+
+    world.check_claimable_prize(FIRST_ADDRESS, 1);
+}
+
+#[test]
+fn lottery_esdt_blackbox_lottery_results() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.check_lottery_history_length(0);
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner();
+
+    world.check_lottery_results(1, &[FIRST_ADDRESS, SECOND_ADDRESS]);
+
+    world.check_lottery_history_length(1);
+
+    world.start_lottery(LotteryStart::new());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.determine_winner();
+
+    world.check_lottery_results(2, &[FIRST_ADDRESS, FIRST_ADDRESS]);
+
+    world.check_lottery_history_length(2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_lottery_events() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_with_logs(LotteryStart::new().whitelist(Some(&[FIRST_ADDRESS])).recurring(false), ExpectLogs(vec![event_log(
+        "lotteryStarted",
+        &["str:test", "address:OWNER_ADDRESS", "", "", "u16:7500|u16:2500", "1", ""],
+        &["nested:str:BSK-476470|biguint:1|u32:2|u64:20|u32:2|u32:2|u8:75|u8:25|biguint:0"],
+    )]));
+
+    world.buy_tickets_with_logs(FIRST_ADDRESS, 2, ExpectLogs(vec![event_log(
+        "ticketBought",
+        &["str:test", "address:FIRST_ADDRESS", "2", "1", "2", ""],
+        &[""],
+    )]));
+
+    // the 2nd place gets 25% of 2 tokens, which rounds down to a prize of 0 that is not logged
+    world.determine_winner_with_logs(ExpectLogs(vec![
+        event_log(
+            "prizePaid",
+            &["str:test", "1", "address:FIRST_ADDRESS", "str:BSK-476470"],
+            &["2"],
+        ),
+        event_log("lotteryCleared", &["str:test"], &[""]),
+    ]));
+}
+
+#[test]
+fn lottery_esdt_blackbox_recurring_lottery_events() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_with_logs(LotteryStart::new().whitelist(Some(&[])).recurring(true), ExpectLogs(vec![event_log(
+        "lotteryStarted",
+        &["str:test", "address:OWNER_ADDRESS", "", "", "u16:7500|u16:2500", "", "u8:1|u32:2|u64:10"],
+        &["nested:str:BSK-476470|biguint:1|u32:2|u64:20|u32:2|u32:2|u8:75|u8:25|biguint:0"],
+    )]));
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.set_time_block(15);
+
+    world.determine_winner_with_logs(ExpectLogs(vec![
+        event_log(
+            "prizePaid",
+            &["str:test", "1", "address:FIRST_ADDRESS", "str:BSK-476470"],
+            &["2"],
+        ),
+        event_log(
+            "lotteryStarted",
+            &["str:test", "address:OWNER_ADDRESS", "", "", "u16:7500|u16:2500", "", "u8:1|u32:2|u64:10"],
+            &["nested:str:BSK-476470|biguint:1|u32:2|u64:25|u32:2|u32:2|u8:75|u8:25|biguint:0"],
+        ),
+    ]));
+}
+
+#[test]
+fn lottery_esdt_blackbox_recurring_lottery() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().prize_distribution(None).whitelist(None).recurring(true));
+
+    world.check_lottery_round(1, 20);
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.set_time_block(15);
+
+    world.determine_winner();
+
+    world.check_status(proxy::Status::Running);
+
+    world.check_lottery_round(2, 25);
+
+    world.check_lottery_history_length(1);
+
+    world.buy_tickets(SECOND_ADDRESS, 2, false);
+
+    world.stop_recurrence_error(SECOND_ADDRESS, ExpectError(4, "Only the lottery creator or the owner can stop the recurrence!"));
+
+    world.stop_recurrence(OWNER_ADDRESS);
+
+    world.stop_recurrence_error(OWNER_ADDRESS, ExpectError(4, "Lottery is not recurring!"));
+
+    world.determine_winner();
+
+    world.check_status(proxy::Status::Inactive);
+
+    world.check_lottery_results(2, &[SECOND_ADDRESS]);
+
+    world.check_lottery_history_length(2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_buy_many_tickets() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.world.set_esdt_balance(FIRST_ADDRESS, b"BSK-476470", 1_000_000u64);
+
+    world.world.set_esdt_balance(SECOND_ADDRESS, b"BSK-476470", 1_000_000u64);
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.buy_tickets(FIRST_ADDRESS, 400_000, false);
+
+    world.buy_tickets(SECOND_ADDRESS, 1, false);
+
+    world.buy_tickets(FIRST_ADDRESS, 599_999, false);
+
+    world.buy_tickets_error(SECOND_ADDRESS, 1, ExpectError(4, "Lottery entry period has ended! Awaiting winner announcement."));
+
+    world.determine_winner();
+
+    world.check_single_winner(&[(FIRST_ADDRESS, 400_000), (SECOND_ADDRESS, 400_001), (FIRST_ADDRESS, 1_000_000)]);
+
+    world.claim_prize(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1_000_001);
+}
+
+#[test]
+fn lottery_esdt_blackbox_basis_points_distribution() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_error(LotteryStart::new().ticket_price(100).total_tickets(Some(3)).max_entries_per_user(Some(1)).whitelist(None).basis_points(&[3333, 3333, 3333]), ExpectError(4, "Prize distribution must add up to exactly 10000 basis points!"));
+
+    world.start_lottery(LotteryStart::new().ticket_price(100).total_tickets(Some(3)).max_entries_per_user(Some(1)).whitelist(None).basis_points(&[3333, 3333, 3334]));
+
+    world.buy_tickets(FIRST_ADDRESS, 100, false);
+
+    world.buy_tickets(SECOND_ADDRESS, 100, false);
+
+    world.buy_tickets(THIRD_ADDRESS, 100, false);
+
+    world.determine_winner();
+
+    // the 1st place gets what is left after the other prizes are rounded down
+    world.check_prizes_by_rank(1, &[101, 99, 100]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_ticket_tier_max_weight() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.add_ticket_tier(1, 3000, 1_000_000, 1);
+
+    // 900 tickets of the tier take more draw slots than a 32-bit usize can count
+    world.buy_tier_tickets(FIRST_ADDRESS, 1, 900);
+
+    world.buy_tier_tickets(SECOND_ADDRESS, 1, 900);
+
+    world.buy_tier_tickets(THIRD_ADDRESS, 1, 900);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_lottery_history_length(1);
+}
+
+#[test]
+fn lottery_esdt_blackbox_ticket_tiers() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.add_ticket_tier_error(FIRST_ADDRESS, 10, 2, 1000, ExpectError(4, "Only the lottery creator can change the lottery!"));
+
+    world.add_ticket_tier_error(OWNER_ADDRESS, 10, 2, 0, ExpectError(4, "Tier weight must be higher than 0!"));
+
+    world.add_ticket_tier_error(OWNER_ADDRESS, 10, 2, 1_000_001, ExpectError(4, "Tier weight can't exceed the maximum tickets per lottery!"));
+
+    world.add_ticket_tier(10, 2, 1000, 1);
+
+    world.buy_tier_tickets_error(FIRST_ADDRESS, 2, 10, ExpectError(4, "Invalid ticket tier!"));
+
+    world.buy_tier_tickets_error(FIRST_ADDRESS, 1, 5, ExpectError(4, "Wrong ticket fee!"));
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_tier_tickets(SECOND_ADDRESS, 1, 10);
+
+    world.buy_tier_tickets_error(THIRD_ADDRESS, 1, 20, ExpectError(4, "Not enough tickets left!"));
+
+    world.add_ticket_tier_error(OWNER_ADDRESS, 10, 2, 1000, ExpectError(4, "Ticket tiers can only be added before any ticket is sold!"));
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    // the tier ticket takes 1000 of the 1001 draw slots
+    world.check_single_winner(&[(FIRST_ADDRESS, 1), (SECOND_ADDRESS, 2)]);
+
+    world.check_claimable_prize(SECOND_ADDRESS, 11);
+
+    world.claim_prize(SECOND_ADDRESS);
+
+    world.check_esdt_balance(SECOND_ADDRESS, 1001);
+}
+
+#[test]
+fn lottery_esdt_blackbox_ticket_tiers_distinct_winners() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(2));
+
+    world.add_ticket_tier(1, 2, 3, 1);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_tier_tickets(SECOND_ADDRESS, 1, 1);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_lottery_results(1, &[FIRST_ADDRESS, SECOND_ADDRESS]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_ticket_tiers_refund() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(4));
+
+    world.add_ticket_tier(5, 4, 2, 1);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_tier_tickets(FIRST_ADDRESS, 1, 5);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 994);
+
+    world.set_time_block(30);
+
+    world.check_status(proxy::Status::Failed);
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1000);
+}
+
+#[test]
+fn lottery_esdt_blackbox_merkle_whitelist() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    let first_leaf = merkle_leaf(FIRST_ADDRESS);
+    let second_leaf = merkle_leaf(SECOND_ADDRESS);
+    let merkle_root = merkle_parent(first_leaf, second_leaf);
+
+    world.set_whitelist_merkle_root_error(FIRST_ADDRESS, merkle_root, ExpectError(4, "Only the lottery creator can change the lottery!"));
+
+    let merkle_root_hex: String = merkle_root.iter().map(|byte| format!("{byte:02x}")).collect();
+    world.set_whitelist_merkle_root_with_logs(OWNER_ADDRESS, merkle_root, ExpectLogs(vec![event_log(
+        "whitelistMerkleRootSet",
+        &["str:test", format!("0x{merkle_root_hex}").as_str()],
+        &[""],
+    )]));
+
+    world.buy_ticket_with_proof(FIRST_ADDRESS, &[second_leaf]);
+
+    world.buy_ticket_with_proof(SECOND_ADDRESS, &[first_leaf]);
+
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.buy_ticket_with_proof_error(THIRD_ADDRESS, &[first_leaf], ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.buy_ticket_with_proof_error(THIRD_ADDRESS, &[second_leaf], ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    let third_leaf = merkle_leaf(THIRD_ADDRESS);
+    world.set_whitelist_merkle_root_error(OWNER_ADDRESS, merkle_parent(first_leaf, third_leaf), ExpectError(4, "The whitelist Merkle root can only be set before any ticket is sold!"));
+}
+
+#[test]
+fn lottery_esdt_blackbox_edit_participant_lists() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.check_whitelisted_addresses(0, 10, &[FIRST_ADDRESS, SECOND_ADDRESS]);
+
+    world.check_whitelisted_addresses(1, 1, &[SECOND_ADDRESS]);
+
+    world.check_whitelisted_addresses(2, 10, &[]);
+
+    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.add_to_whitelist(&[THIRD_ADDRESS]);
+
+    world.remove_from_whitelist(&[SECOND_ADDRESS]);
+
+    world.check_whitelisted_addresses(0, 10, &[FIRST_ADDRESS, THIRD_ADDRESS]);
+
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.add_to_blacklist_error(FIRST_ADDRESS, &[THIRD_ADDRESS], ExpectError(4, "Only the lottery creator can change the lottery!"));
+
+    world.add_to_blacklist(OWNER_ADDRESS, &[THIRD_ADDRESS, SECOND_ADDRESS]);
+
+    world.check_blacklisted_addresses(0, 1, &[THIRD_ADDRESS]);
+
+    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4, "You are blacklisted from this lottery!"));
+
+    world.remove_from_blacklist(&[THIRD_ADDRESS]);
+
+    world.check_blacklisted_addresses(0, 10, &[SECOND_ADDRESS]);
+
+    world.buy_ticket(THIRD_ADDRESS);
+
+    world.check_whitelisted_addresses(u32::MAX as usize, 10, &[]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_empty_whitelist_stays_restricted() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.remove_from_whitelist(&[FIRST_ADDRESS, SECOND_ADDRESS]);
+
+    world.check_whitelisted_addresses(0, 10, &[]);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+
+    world.add_to_whitelist(&[THIRD_ADDRESS]);
+
+    world.buy_ticket(THIRD_ADDRESS);
+}
+
+#[test]
+fn lottery_esdt_blackbox_token_gated_lottery() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.set_required_collection_error(FIRST_ADDRESS, NFT_COLLECTION, ExpectError(4, "Only the lottery creator can change the lottery!"));
+
+    world.set_required_collection(OWNER_ADDRESS, NFT_COLLECTION);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4, "A token of the required collection must be sent along with the ticket payment!"));
+
+    world.buy_ticket_with_collection_token_error(THIRD_ADDRESS, TOKEN_BURNABLE, 0, ExpectError(4, "A token of the required collection must be sent along with the ticket payment!"));
+
+    world.buy_ticket_with_collection_token(FIRST_ADDRESS, NFT_COLLECTION, 1);
+
+    world.buy_ticket_with_collection_token(FIRST_ADDRESS, NFT_COLLECTION, 1);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 998);
+
+    world
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_COLLECTION, 1, 1, "");
+}
+
+#[test]
+fn lottery_esdt_blackbox_nft_prize() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.deposit_nft_prize_error(FIRST_ADDRESS, 1, NFT_COLLECTION, 1, ExpectError(4, "Only the lottery creator can change the lottery!"));
+
+    world.deposit_nft_prize_error(OWNER_ADDRESS, 3, NFT_COLLECTION, 2, ExpectError(4, "Invalid prize rank!"));
+
+    world.deposit_nft_prize(1, NFT_COLLECTION, 2);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.deposit_nft_prize_error(OWNER_ADDRESS, 2, NFT_COLLECTION, 2, ExpectError(4, "Prizes can only be deposited before any ticket is sold!"));
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner();
+
+    let first_place_winner = world.get_winner_by_rank(1, 1);
+
+    world.claim_prize(first_place_winner);
+
+    world.check_nft_balance(first_place_winner, 2, 1);
+}
+
+#[test]
+fn lottery_esdt_blackbox_nft_prize_returned_without_tickets() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.deposit_nft_prize(2, NFT_COLLECTION, 2);
+
+    world.check_nft_balance(OWNER_ADDRESS, 2, 1);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.claim_prize(OWNER_ADDRESS);
+
+    world.check_nft_balance(OWNER_ADDRESS, 2, 2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_nft_prize_returned_on_cancel() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.deposit_nft_prize(1, NFT_COLLECTION, 2);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.cancel_lottery(OWNER_ADDRESS);
+
+    // the buyer has not claimed the refund yet
+    world.claim_prize(OWNER_ADDRESS);
+
+    world.check_nft_balance(OWNER_ADDRESS, 2, 2);
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_status(proxy::Status::Inactive);
+}
+
+#[test]
+fn lottery_esdt_blackbox_nft_prize_returned_on_failure() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(4));
+
+    world.deposit_nft_prize(1, NFT_COLLECTION, 2);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.reclaim_nft_prizes_error(SECOND_ADDRESS, ExpectError(4, "Lottery is not refundable!"));
+
+    world.set_time_block(30);
+
+    world.reclaim_nft_prizes(SECOND_ADDRESS);
+
+    world.claim_prize(OWNER_ADDRESS);
+
+    world.check_nft_balance(OWNER_ADDRESS, 2, 2);
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_esdt_balance(FIRST_ADDRESS, 1000);
+}
+
+#[test]
+fn lottery_esdt_blackbox_sponsor_lottery() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.sponsor_lottery_error(THIRD_ADDRESS, TOKEN_BURNABLE, 100, ExpectError(4, "Wrong sponsorship token!"));
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 60);
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 40);
+
+    world.check_sponsored_prize_pool(100);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner();
+
+    world.check_prizes_by_rank(1, &[77, 25]);
+
+    world.check_sponsored_prize_pool(0);
+}
+
+#[test]
+fn lottery_esdt_blackbox_sponsorships_excluded_from_burn() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[EsdtLocalRole::Burn]);
+
+    world.start_lottery(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_BURNABLE)).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(10));
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_BURNABLE, 100);
+
+    world.exclude_sponsorships_from_burn(true);
+
+    world.buy_burnable_tickets(FIRST_ADDRESS, 20);
+
+    world.determine_winner();
+
+    world.check_burn_amount(1, 2);
+
+    world.check_prizes_by_rank(1, &[118]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_sponsorship_refund() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(4));
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 50);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.set_time_block(30);
+
+    world.claim_refund(FIRST_ADDRESS);
+
+    world.check_status(proxy::Status::Failed);
+
+    world.claim_refund(THIRD_ADDRESS);
+
+    world.check_esdt_balance(THIRD_ADDRESS, 1000);
+
+    world.check_status(proxy::Status::Inactive);
+}
+
+#[test]
+fn lottery_esdt_blackbox_rollover_without_tickets() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.enable_rollover(0);
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 100);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_status(proxy::Status::Inactive);
+
+    world.check_rollover_pool(100);
+
+    world.set_time_block(10);
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_prizes_by_rank(2, &[102]);
+
+    world.check_rollover_pool(0);
+}
+
+#[test]
+fn lottery_esdt_blackbox_jackpot_kept_for_its_creator() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.enable_rollover(0);
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 100);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_rollover_pool(100);
+
+    world.set_time_block(10);
+
+    world.start_lottery_error(LotteryStart::unlimited().creator(FIRST_ADDRESS), ExpectError(4, "The jackpot of this lottery name belongs to another creator!"));
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_prizes_by_rank(2, &[102]);
+
+    // once the jackpot is won, the name is free again
+    world.set_time_block(10);
+
+    world.start_lottery(LotteryStart::unlimited().creator(FIRST_ADDRESS));
+}
+
+#[test]
+fn lottery_esdt_blackbox_jackpot_reservation_expires() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited());
+
+    world.enable_rollover(0);
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 100);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.check_rollover_pool(100);
+
+    let reserved_until = 30 + THIRTY_DAYS_IN_SECONDS;
+
+    world.set_time_block(reserved_until);
+
+    world.start_lottery_error(
+        LotteryStart::unlimited()
+            .creator(FIRST_ADDRESS)
+            .deadline(Some(reserved_until + 10)),
+        ExpectError(4, "The jackpot of this lottery name belongs to another creator!"),
+    );
+
+    // the creator never came back, so the name is left to anyone
+    world.set_time_block(reserved_until + 1);
+
+    world.start_lottery(
+        LotteryStart::unlimited()
+            .creator(FIRST_ADDRESS)
+            .deadline(Some(reserved_until + 10)),
+    );
+
+    world.buy_tickets(SECOND_ADDRESS, 2, false);
+
+    world.set_time_block(reserved_until + 20);
+
+    world.determine_winner();
+
+    world.check_prizes_by_rank(2, &[102]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_tickets_reset_after_restart() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)));
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    world.set_time_block(10);
+
+    world.start_lottery(LotteryStart::new());
+
+    // the entries of the previous lottery no longer count towards the limit per user
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.determine_winner();
+
+    world.check_lottery_results(2, &[FIRST_ADDRESS, FIRST_ADDRESS]);
 }
 
 #[test]
-fn lottery_esdt_blackbox_buy_after_deadline()
-{
+fn lottery_esdt_blackbox_rollover_percentage() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
 
-    world.start_lottery();
+    world.start_lottery(LotteryStart::new());
+
+    world.enable_rollover_error(100, ExpectError(4, "Invalid rollover percentage!"));
+
+    world.enable_rollover(50);
 
     world.buy_ticket(FIRST_ADDRESS);
 
     world.buy_ticket(SECOND_ADDRESS);
 
-    world.set_time_block(30);
-    
-    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+    world.determine_winner();
 
+    world.check_prizes_by_rank(1, &[1, 0]);
+
+    world.check_rollover_pool(1);
 }
 
 #[test]
-fn lottery_esdt_blackbox_buy_after_sold_out()
-{
+fn lottery_esdt_blackbox_scheduled_start() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
 
-    world.start_lottery();
-    
+    world.start_lottery_error(LotteryStart::unlimited().deadline(Some(100)).start_time(5), ExpectError(4, "Start time can't be in the past!"));
+
+    world.start_lottery_error(LotteryStart::unlimited().deadline(Some(50)).start_time(100), ExpectError(4, "Deadline must be after the start time!"));
+
+    world.start_lottery(LotteryStart::unlimited().deadline(Some(100)).start_time(50));
+
+    world.check_status(proxy::Status::Pending);
+
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Lottery is already active!"));
+
+    world.add_ticket_tier(10, 2, 5, 1);
+
+    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4, "Lottery has not opened yet!"));
+
+    world.determine_winner_error(ExpectError(4, "Lottery has not opened yet!"));
+
+    world.set_time_block(50);
+
+    world.check_status(proxy::Status::Running);
+
     world.buy_ticket(FIRST_ADDRESS);
 
+    world.set_time_block(101);
+
+    world.determine_winner();
+
+    world.check_claimable_prize(FIRST_ADDRESS, 1);
+}
+
+#[test]
+fn lottery_esdt_blackbox_extend_lottery() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.extend_deadline_error(15, ExpectError(4, "The new deadline must be later than the current one!"));
+    world.extend_deadline_error(100000000, ExpectError(4, "Deadline can't be later than the maximum duration from the start!"));
+    world.extend_deadline_with_logs(30, ExpectLogs(vec![event_log(
+        "deadlineExtended",
+        &["str:test", "30"],
+        &[""],
+    )]));
+
+    world.set_time_block(25);
+    world.check_status(proxy::Status::Running);
+
+    world.increase_ticket_supply_error(0, ExpectError(4, "Must add more than 0 tickets!"));
+    world.increase_ticket_supply_error(999_999, ExpectError(4, "Total tickets can't exceed the maximum per lottery!"));
+    world.increase_ticket_supply_with_logs(1, ExpectLogs(vec![event_log(
+        "ticketSupplyIncreased",
+        &["str:test", "1", "3"],
+        &[""],
+    )]));
+
+    world.buy_ticket(FIRST_ADDRESS);
+    world.buy_ticket(FIRST_ADDRESS);
     world.buy_ticket(SECOND_ADDRESS);
+    world.check_status(proxy::Status::Ended);
 
-    world.buy_ticket_error(FIRST_ADDRESS, ExpectError(4,"Lottery entry period has ended! Awaiting winner announcement."));
+    world.extend_deadline_error(40, ExpectError(4, "Lottery is not running!"));
+    world.increase_ticket_supply_error(1, ExpectError(4, "Lottery is not running!"));
+}
+
+#[test]
+fn lottery_esdt_blackbox_keeper_reward_percentage() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.set_keeper_reward_error(proxy::KeeperReward::Percentage(BigUint::from(100u64)), ExpectError(4, "Invalid keeper reward percentage!"));
+    world.set_keeper_reward_error(proxy::KeeperReward::FixedAmount(BigUint::zero()), ExpectError(4, "Keeper reward must be higher than 0!"));
+    world.set_keeper_reward(proxy::KeeperReward::Percentage(BigUint::from(10u64)));
+
+    world.sponsor_lottery(THIRD_ADDRESS, TOKEN_IDENTIFIER, 98);
+
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.set_keeper_reward_error(proxy::KeeperReward::Percentage(BigUint::from(20u64)), ExpectError(4, "The keeper reward can only be set before any ticket is sold!"));
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner_from(THIRD_ADDRESS);
 
+    world.check_claimable_prize(THIRD_ADDRESS, 10);
+
+    world.check_prizes_by_rank(1, &[68, 22]);
 }
 
 #[test]
-fn lottery_esdt_blackbox_buy_not_whitelisted()
-{
+fn lottery_esdt_blackbox_keeper_reward_fixed_amount() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
 
-    world.start_lottery();
-    
-    world.buy_ticket_error(THIRD_ADDRESS, ExpectError(4, "You are not allowed to participate in this lottery!"));
+    world.start_lottery(LotteryStart::new());
+
+    world.set_keeper_reward(proxy::KeeperReward::FixedAmount(BigUint::from(5u64)));
 
+    world.buy_ticket(FIRST_ADDRESS);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner_from(THIRD_ADDRESS);
+
+    world.check_claimable_prize(THIRD_ADDRESS, 2);
+
+    world.check_prizes_by_rank(1, &[0, 0]);
 }
 
 #[test]
-fn lottery_esdt_blackbox_buy_wrong_fee()
-{
+fn lottery_esdt_blackbox_config_limits() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
 
-    world.start_lottery();
-    
-    world.buy_ticket_wrong_fee(FIRST_ADDRESS, BigUint::<StaticApi>::from(2u128));
+    world.set_max_tickets_error(FIRST_ADDRESS, 1, ExpectError(4, "Endpoint can only be called by owner"));
+
+    world.set_max_tickets(OWNER_ADDRESS, 1);
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Total tickets can't exceed the maximum per lottery!"));
+    world.set_max_tickets(OWNER_ADDRESS, 2);
+
+    world.set_max_duration(5);
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Deadline can't be later than the maximum duration from now!"));
+    world.set_max_duration(10);
+
+    world.add_allowed_ticket_token(TOKEN_BURNABLE);
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Ticket token is not allowed!"));
+    world.add_allowed_ticket_token(TOKEN_IDENTIFIER);
 
+    world.set_min_ticket_price(TOKEN_IDENTIFIER, 2);
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Ticket price is lower than the minimum!"));
+    world.set_min_ticket_price(TOKEN_IDENTIFIER, 1);
+
+    world.start_lottery(LotteryStart::new());
+
+    world.increase_ticket_supply_error(1, ExpectError(4, "Total tickets can't exceed the maximum per lottery!"));
+    world.extend_deadline_error(21, ExpectError(4, "Deadline can't be later than the maximum duration from the start!"));
+
+    // the bound doesn't move with time, so the deadline can't be pushed back again and again
+    world.set_time_block(15);
+    world.extend_deadline_error(21, ExpectError(4, "Deadline can't be later than the maximum duration from the start!"));
 }
 
 #[test]
-fn lottery_esdt_blackbox_determine_winner_early()
-{
+fn lottery_esdt_blackbox_pause_contract() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
-    
-    world.start_lottery();
-    
+
+    world.pause_contract_error(FIRST_ADDRESS, ExpectError(4, "Endpoint can only be called by owner"));
+    world.pause_contract(OWNER_ADDRESS);
+
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4, "Contract is paused"));
+    world.start_lottery_error(LotteryStart::unlimited().deadline(None).pool(), ExpectError(4, "Contract is paused"));
+    world.start_lottery_error(LotteryStart::unlimited().basis_points(&[10_000]), ExpectError(4, "Contract is paused"));
+
+    world.unpause_contract();
+    world.start_lottery(LotteryStart::new());
     world.buy_ticket(FIRST_ADDRESS);
+    world.pause_contract(OWNER_ADDRESS);
 
-    world.determine_winner_error(ExpectError(4,"Lottery is still running!"));
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4, "Contract is paused"));
+    world.sponsor_lottery_error(THIRD_ADDRESS, TOKEN_IDENTIFIER, 10, ExpectError(4, "Contract is paused"));
+
+    world.set_time_block(21);
+    world.determine_winner_error(ExpectError(4, "Contract is paused"));
 
+    world.unpause_contract();
+    world.determine_winner();
+    world.pause_contract(OWNER_ADDRESS);
+
+    world.claim_prize(FIRST_ADDRESS);
+    world.check_claimable_prize(FIRST_ADDRESS, 0);
 }
 
 #[test]
-fn lottery_esdt_blackbox_determine_winner_one_participant() {
+fn lottery_esdt_blackbox_pause_contract_refunds() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(3)).whitelist(None).min_tickets(2));
+    world.buy_ticket(FIRST_ADDRESS);
+    world.pause_contract(OWNER_ADDRESS);
+
+    world.cancel_lottery(OWNER_ADDRESS);
+    world.claim_refund(FIRST_ADDRESS);
+    world.check_status(proxy::Status::Inactive);
+}
+
+#[test]
+fn lottery_esdt_blackbox_pause_lottery() {
     let mut world = LotteryESDTTestState::new();
 
     world.deploy();
 
-    world.start_lottery();
+    world.pause_lottery_error(ExpectError(4, "Lottery is inactive!"));
+
+    world.start_lottery(LotteryStart::new());
+    world.unpause_lottery_error(ExpectError(4, "Lottery is not paused!"));
+    world.buy_ticket(FIRST_ADDRESS);
+    world.pause_lottery();
+
+    world.buy_ticket_error(SECOND_ADDRESS, ExpectError(4, "Lottery is paused!"));
+    world.sponsor_lottery_error(THIRD_ADDRESS, TOKEN_IDENTIFIER, 10, ExpectError(4, "Lottery is paused!"));
+
+    world.set_time_block(21);
+    world.determine_winner_error(ExpectError(4, "Lottery is paused!"));
 
+    world.unpause_lottery();
+    world.determine_winner();
+
+    world.claim_prize(FIRST_ADDRESS);
+    world.check_claimable_prize(FIRST_ADDRESS, 0);
+}
+
+#[test]
+fn lottery_esdt_blackbox_pause_lottery_refunds() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
     world.buy_ticket(FIRST_ADDRESS);
+    world.pause_lottery();
+
+    world.cancel_lottery(OWNER_ADDRESS);
+    world.claim_refund(FIRST_ADDRESS);
+    world.check_status(proxy::Status::Inactive);
 
+    world.start_lottery(LotteryStart::new());
     world.buy_ticket(FIRST_ADDRESS);
+}
+
+#[test]
+fn lottery_esdt_blackbox_set_roles_owner_only() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.set_roles_error(FIRST_ADDRESS, ExpectError(4, "Endpoint can only be called by owner"));
+
+    world.check_burn_capable_tokens(&[]);
+}
+
+#[test]
+fn lottery_esdt_blackbox_set_roles_failure() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.fail_set_roles_callback(10, "action is not allowed");
+
+    world.check_burn_capable_tokens(&[]);
+
+    world.start_lottery_error(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_BURNABLE)).burn_percentage(10), ExpectError(4, "The Burn role has not been granted for the selected token! It can be requested with set_roles."));
+}
+
+#[test]
+fn lottery_esdt_blackbox_fee_destination() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_IDENTIFIER)).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(10).fee_destination(THIRD_ADDRESS));
+
+    world.buy_tickets(FIRST_ADDRESS, 20, false);
 
     world.determine_winner();
-    
+
+    world.check_burn_amount(1, 2);
+    world.check_prizes_by_rank(1, &[18]);
+    world.check_claimable_prize(THIRD_ADDRESS, 2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_fee_destination_egld() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery_error(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::egld()).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(0).fee_destination(THIRD_ADDRESS), ExpectError(4, "A fee destination needs a burn percentage!"));
+
+    world.start_lottery(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::egld()).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(10).fee_destination(THIRD_ADDRESS));
+
+    world.buy_egld_tickets(FIRST_ADDRESS, 20);
+
+    world.determine_winner();
+
+    world.claim_prize(THIRD_ADDRESS);
+    world.world.check_account(THIRD_ADDRESS).balance(2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_burn_role_revoked() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[EsdtLocalRole::Burn]);
+
+    world.start_lottery(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_BURNABLE)).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(10));
+
+    world.buy_burnable_tickets(FIRST_ADDRESS, 20);
+
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[]);
+
+    world.determine_winner();
+
+    world.claim_prize(OWNER_ADDRESS);
+    world.world.check_account(OWNER_ADDRESS).esdt_balance(TOKEN_BURNABLE, 2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_burn_role_revoked_fallback() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.set_fallback_fee_destination(SECOND_ADDRESS);
+
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[EsdtLocalRole::Burn]);
+
+    world.start_lottery(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_BURNABLE)).ticket_price(10).prize_distribution(None).whitelist(None).burn_percentage(10));
+
+    world.buy_burnable_tickets(FIRST_ADDRESS, 20);
+
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[]);
+
+    world.determine_winner();
+
+    world.claim_prize(SECOND_ADDRESS);
+    world.world.check_account(SECOND_ADDRESS).esdt_balance(TOKEN_BURNABLE, 2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_unique_winners() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(3)).whitelist(None).min_tickets(0));
+
+    world.enable_unique_winners();
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.enable_unique_winners_error(ExpectError(4, "Unique winners can only be enabled before any ticket is sold!"));
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.determine_winner();
+
+    let first_place = world.get_winner_by_rank(1, 1);
+    let second_place = world.get_winner_by_rank(1, 2);
+    assert!(first_place != second_place);
+}
+
+#[test]
+fn lottery_esdt_blackbox_unique_winners_tiers() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().total_tickets(Some(4)).whitelist(None).min_tickets(0));
+
+    world.add_ticket_tier(1, 2, 3, 1);
+
+    world.enable_unique_winners();
+
+    world.buy_tier_tickets(FIRST_ADDRESS, 1, 2);
+
+    world.buy_ticket(SECOND_ADDRESS);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    let first_place = world.get_winner_by_rank(1, 1);
+    let second_place = world.get_winner_by_rank(1, 2);
+    assert!(first_place != second_place);
+}
+
+#[test]
+fn lottery_esdt_blackbox_unique_winners_single_holder() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new());
+
+    world.enable_unique_winners();
+
+    world.buy_tickets(FIRST_ADDRESS, 2, false);
+
+    world.determine_winner();
+
+    world.check_prizes_by_rank(1, &[2]);
+    world.check_claimable_prize(FIRST_ADDRESS, 2);
+}
+
+#[test]
+fn lottery_esdt_blackbox_unique_winners_bounded_draws() {
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::unlimited().prize_distribution(Some(&[50, 30, 20])));
+
+    world.enable_unique_winners();
+
+    world.buy_tickets(FIRST_ADDRESS, 998, false);
+    world.buy_ticket(SECOND_ADDRESS);
+    world.buy_ticket(THIRD_ADDRESS);
+
+    world.set_time_block(30);
+
+    world.determine_winner();
+
+    // the other holders have too few tickets to be drawn again and again, so they are drawn among themselves
+    world.check_prizes_by_rank(1, &[500, 300, 200]);
+    assert!(world.get_winner_by_rank(1, 1) == FIRST_ADDRESS);
+    let second_place = world.get_winner_by_rank(1, 2);
+    let third_place = world.get_winner_by_rank(1, 3);
+    assert!(second_place == SECOND_ADDRESS || second_place == THIRD_ADDRESS);
+    assert!(third_place == SECOND_ADDRESS || third_place == THIRD_ADDRESS);
+    assert!(second_place != third_place);
+}
+
+#[test]
+fn lottery_esdt_blackbox_creator_only_endpoints()
+{
+    let mut world = LotteryESDTTestState::new();
+
+    world.deploy();
+
+    world.start_lottery(LotteryStart::new().creator(FIRST_ADDRESS));
+
+    // the contract owner is not the creator of this lottery
+    world.enable_rollover_error(10, ExpectError(4, "Only the lottery creator can change the lottery!"));
+    world.enable_unique_winners_error(ExpectError(4, "Only the lottery creator can change the lottery!"));
+    world.extend_deadline_error(30, ExpectError(4, "Only the lottery creator can change the lottery!"));
+    world.increase_ticket_supply_error(1, ExpectError(4, "Only the lottery creator can change the lottery!"));
+    world.deposit_nft_prize_error(OWNER_ADDRESS, 1, NFT_COLLECTION, 2, ExpectError(4, "Only the lottery creator can change the lottery!"));
 }
 
 #[test]
@@ -440,9 +3312,9 @@ fn lottery_esdt_blackbox_start_lottery_twice()
 
     world.deploy();
 
-    world.start_lottery();
+    world.start_lottery(LotteryStart::new());
 
-    world.start_lottery_error(ExpectError(4,"Lottery is already active!"));
+    world.start_lottery_error(LotteryStart::new(), ExpectError(4,"Lottery is already active!"));
 
 }
 
@@ -453,35 +3325,30 @@ fn lottery_esdt_blackbox_wrong_start_params()
 
     world.deploy();
     
-    let wrong_total_tickets = Option::Some(0u32);
-    let wrong_total_tickets2 = Option::Some(900u32);
-    let wrong_deadline = Option::Some(0u64);
-    let wrong_deadline2 = Option::Some(100000000u64);
-    let total_tickets = Option::Some(2u32);
-    let deadline = Option::Some(20u64);
+    world.world.set_esdt_local_roles(SC_ADDRESS, b"TEST-123456", &[EsdtLocalRole::Burn]);
 
-    world.start_lottery_error_params(true, 0, false, total_tickets, deadline, false, false, false, ExpectError(4,"Name can't be empty!"));
+    world.start_lottery_error(LotteryStart::new().lottery_name(""), ExpectError(4,"Name can't be empty!"));
 
-    world.start_lottery_error_params(false, 1, false, total_tickets, deadline, false, false, false, ExpectError(4,"Invalid token name provided!"));
+    world.start_lottery_error(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt("")), ExpectError(4,"Invalid token name provided!"));
 
-    world.start_lottery_error_params(false, 0, true, total_tickets, deadline, false, false, false, ExpectError(4,"Ticket price must be higher than 0!"));
+    world.start_lottery_error(LotteryStart::new().ticket_price(0), ExpectError(4,"Ticket price must be higher than 0!"));
 
-    world.start_lottery_error_params(false, 0, false, wrong_total_tickets, deadline, false, false, false, ExpectError(4,"Must have more than 0 tickets available!"));
+    world.start_lottery_error(LotteryStart::new().total_tickets(Some(0)), ExpectError(4,"Must have more than 0 tickets available!"));
 
-    world.start_lottery_error_params(false, 0, false, wrong_total_tickets2, deadline, false, false, false, ExpectError(4,"Only 800 or less total tickets per lottery are allowed!"));
+    world.start_lottery_error(LotteryStart::new().total_tickets(Some(1_000_001)), ExpectError(4,"Total tickets can't exceed the maximum per lottery!"));
 
-    world.start_lottery_error_params(false, 0, false, total_tickets, wrong_deadline, false, false, false, ExpectError(4,"Deadline can't be in the past!"));
+    world.start_lottery_error(LotteryStart::new().deadline(Some(0)), ExpectError(4,"Deadline can't be in the past!"));
 
-    world.start_lottery_error_params(false, 0, false, total_tickets, wrong_deadline2, false, false, false, ExpectError(4,"Deadline can't be later than 30 days from now!"));
+    world.start_lottery_error(LotteryStart::new().deadline(Some(100000000)), ExpectError(4,"Deadline can't be later than the maximum duration from now!"));
 
-    world.start_lottery_error_params(false, 0, false,total_tickets, deadline, true, false, false, ExpectError(4,"Must have more than 0 max entries per user!"));
+    world.start_lottery_error(LotteryStart::new().max_entries_per_user(Some(0)), ExpectError(4,"Must have more than 0 max entries per user!"));
 
-    world.start_lottery_error_params(false, 0, false, total_tickets, deadline, false, true, false, ExpectError(4,"Prize distribution must add up to exactly 100(%)!"));
+    world.start_lottery_error(LotteryStart::new().prize_distribution(Some(&[60, 60])), ExpectError(4,"Prize distribution must add up to exactly 100(%)!"));
 
-    world.start_lottery_error_params(false, 2, false, total_tickets, deadline, false, false, true, ExpectError(4,"EGLD can't be burned!"));
+    world.start_lottery_error(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::egld()).burn_percentage(10), ExpectError(4,"EGLD can't be burned!"));
 
-    world.start_lottery_error_params(false, 0, false, total_tickets, deadline, false, false, true, ExpectError(4,"The contract can't burn the selected token!"));
+    world.start_lottery_error(LotteryStart::new().burn_percentage(10), ExpectError(4,"The Burn role has not been granted for the selected token! It can be requested with set_roles."));
 
-    world.start_lottery_error_params(false, 3, false, total_tickets, deadline, false, false, true, ExpectError(4,"Invalid burn percentage!"));
+    world.start_lottery_error(LotteryStart::new().token(EgldOrEsdtTokenIdentifier::esdt(TOKEN_BURNABLE)).burn_percentage(101), ExpectError(4,"Invalid burn percentage!"));
 
 }
@@ -119,11 +119,31 @@ fn init_lottery_esdt_go() {
     world().run("scenarios/init-lottery-esdt.scen.json");
 }
 
+#[test]
+fn legacy_lottery_info_go() {
+    world().run("scenarios/legacy-lottery-info.scen.json");
+}
+
+#[test]
+fn legacy_tickets_draw_go() {
+    world().run("scenarios/legacy-tickets-draw.scen.json");
+}
+
+#[test]
+fn legacy_tickets_refund_go() {
+    world().run("scenarios/legacy-tickets-refund.scen.json");
+}
+
 #[test]
 fn lottery_init_go() {
     world().run("scenarios/lottery-init.scen.json");
 }
 
+#[test]
+fn lottery_with_burn_basis_points_go() {
+    world().run("scenarios/lottery-with-burn-basis-points.scen.json");
+}
+
 #[test]
 fn lottery_with_burn_percentage_go() {
     world().run("scenarios/lottery-with-burn-percentage.scen.json");
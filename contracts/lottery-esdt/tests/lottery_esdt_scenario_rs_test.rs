@@ -130,11 +130,31 @@ fn init_lottery_esdt_rs() {
     world().run("scenarios/init-lottery-esdt.scen.json");
 }
 
+#[test]
+fn legacy_lottery_info_rs() {
+    world().run("scenarios/legacy-lottery-info.scen.json");
+}
+
+#[test]
+fn legacy_tickets_draw_rs() {
+    world().run("scenarios/legacy-tickets-draw.scen.json");
+}
+
+#[test]
+fn legacy_tickets_refund_rs() {
+    world().run("scenarios/legacy-tickets-refund.scen.json");
+}
+
 #[test]
 fn lottery_init_rs() {
     world().run("scenarios/lottery-init.scen.json");
 }
 
+#[test]
+fn lottery_with_burn_basis_points_rs() {
+    world().run("scenarios/lottery-with-burn-basis-points.scen.json");
+}
+
 #[test]
 fn lottery_with_burn_percentage_rs() {
     world().run("scenarios/lottery-with-burn-percentage.scen.json");
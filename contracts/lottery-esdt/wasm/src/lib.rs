@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
-// Async Callback (empty):               1
-// Total number of exported functions:  10
+// Endpoints:                           74
+// Async Callback:                       1
+// Total number of exported functions:  76
 
 #![no_std]
 
@@ -20,13 +20,79 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         start => start
         createLotteryPool => create_lottery_pool
+        startWithBasisPoints => start_with_basis_points
         buy_ticket => buy_ticket
         determine_winner => determine_winner
+        claimPrize => claim_prize
+        sponsorLottery => sponsor_lottery
+        excludeSponsorshipsFromBurn => exclude_sponsorships_from_burn
+        enableRollover => enable_rollover
+        enableUniqueWinners => enable_unique_winners
+        setKeeperReward => set_keeper_reward
+        getSponsorships => get_sponsorships
+        depositNftPrize => deposit_nft_prize
+        addTicketTier => add_ticket_tier
+        setWhitelistMerkleRoot => set_whitelist_merkle_root
+        addToWhitelist => add_to_whitelist
+        removeFromWhitelist => remove_from_whitelist
+        addToBlacklist => add_to_blacklist
+        removeFromBlacklist => remove_from_blacklist
+        setRequiredCollection => set_required_collection
+        pauseLottery => pause_lottery
+        unpauseLottery => unpause_lottery
+        cancelLottery => cancel_lottery
+        stopRecurrence => stop_recurrence
+        extendDeadline => extend_deadline
+        increaseTicketSupply => increase_ticket_supply
+        claimRefund => claim_refund
+        reclaimNftPrizes => reclaim_nft_prizes
         status => status
+        getLotteryResults => get_lottery_results
+        getLotteryHistory => get_lottery_history
+        getClaimablePrizes => get_claimable_prizes
+        getLotteryCreator => get_lottery_creator
+        getPrizeDistributionBasisPoints => get_prize_distribution_basis_points_view
+        getWhitelistedAddresses => get_whitelisted_addresses
+        getBlacklistedAddresses => get_blacklisted_addresses
         set_roles => set_roles
+        getGrantedRoles => granted_roles
+        getBurnCapableTokens => burn_capable_tokens
         getLotteryInfo => lottery_info
         getLotteryWhitelist => lottery_whitelist
+        isWhitelistEnabled => whitelist_enabled
+        getRequiredCollection => required_collection
+        getWhitelistMerkleRoot => whitelist_merkle_root
+        getFeeDestination => fee_destination
+        getTicketTiers => ticket_tiers
+        isLotteryPaused => lottery_paused
+        getLotteryStartTime => lottery_start_time
+        getMinTickets => min_tickets
+        getLotteryRecurrence => lottery_recurrence
+        getRound => round
+        getSponsoredPrizePool => sponsored_prize_pool
+        areSponsorshipsExcludedFromBurn => sponsorships_excluded_from_burn
+        areWinnersUnique => unique_winners
+        getKeeperReward => keeper_reward
+        getRolloverPercentageForLottery => rollover_percentage_for_lottery
+        getRolloverPool => rollover_pool
+        getRolloverPoolCreator => rollover_pool_creator
+        getRolloverPoolReservedUntil => rollover_pool_reserved_until
+        getNftPrizes => nft_prizes
+        setMaxDuration => set_max_duration
+        setMaxTickets => set_max_tickets
+        addAllowedTicketTokens => add_allowed_ticket_tokens
+        removeAllowedTicketTokens => remove_allowed_ticket_tokens
+        setMinTicketPrice => set_min_ticket_price
+        setFallbackFeeDestination => set_fallback_fee_destination
+        getMaxDuration => get_max_duration
+        getMaxTickets => get_max_tickets
+        getFallbackFeeDestination => get_fallback_fee_destination
+        getAllowedTicketTokens => allowed_ticket_tokens
+        getMinTicketPrice => min_ticket_price
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { lottery_esdt }